@@ -1,8 +1,35 @@
 use matrix_oxide::Matrix;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::ops::{Add, Mul};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
+/// Number of heap allocations made so far, counted by `CountingAllocator`
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting every allocation it makes
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
 fn main() {
     bench_matrix_multiplication();
+    bench_blocked_matrix_multiplication();
+    bench_strassen_matrix_multiplication();
+    bench_inplace_training_step();
     bench_matrix_inversion();
 }
 
@@ -74,6 +101,208 @@ fn bench_matrix_multiplication() {
     );
 }
 
+/// The textbook i-j-k matrix multiplication `Matrix::multiply` used before the
+/// cache blocked kernel, kept here as the baseline to measure the kernel against.
+fn naive_multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> Vec<T>
+where
+    T: Default + Clone + Add<Output = T> + Mul<Output = T>,
+{
+    (0..a.row_size)
+        .flat_map(|i| {
+            (0..b.col_size).map(move |j| {
+                (0..a.col_size)
+                    .map(|k| {
+                        a.data[i * a.col_size + k].clone() * b.data[k * b.col_size + j].clone()
+                    })
+                    .fold(T::default(), |acc, x| acc + x)
+            })
+        })
+        .collect()
+}
+
+/// Time the naive and the blocked multiplication of 2 matrices
+fn time_multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> (Duration, Duration)
+where
+    T: Default + Clone + Add<Output = T> + Mul<Output = T> + std::ops::Sub<Output = T>,
+{
+    let start = Instant::now();
+    let _naive = naive_multiply(a, b);
+    let naive = start.elapsed();
+
+    let start = Instant::now();
+    let _blocked = a.multiply(b).expect(
+        "Matrix Multiplication To Work. If you're reading this open an issue:
+        https://github.com/antonio-hickey/matrix-oxide/issues",
+    );
+    let blocked = start.elapsed();
+
+    (naive, blocked)
+}
+
+/// Measured with `cargo run --release` (from `benchmarks/`, rustc 1.95) on a
+/// 1 vCPU Intel Xeon VM (x86_64-unknown-linux-gnu), single runs so expect some noise:
+///
+/// | size    | type | naive    | blocked  | speedup |
+/// |---------|------|----------|----------|---------|
+/// | 100     | f32  | 855µs    | 132µs    | 6.5x    |
+/// | 100     | f64  | 899µs    | 252µs    | 3.6x    |
+/// | 256     | f32  | 25.2ms   | 4.0ms    | 6.3x    |
+/// | 256     | f64  | 27.8ms   | 5.9ms    | 4.7x    |
+/// | 512     | f32  | 181ms    | 19.1ms   | 9.5x    |
+/// | 512     | f64  | 573ms    | 47.5ms   | 12.1x   |
+/// | 1024    | f32  | 9.10s    | 236ms    | 38.6x   |
+/// | 1024    | f64  | 11.63s   | 298ms    | 39.0x   |
+fn bench_blocked_matrix_multiplication() {
+    println!("Benchmarking blocked matrix multiplication against the naive i-j-k loop...");
+
+    for size in [100, 256, 512, 1024] {
+        let a: Matrix<f64> = Matrix::new_random(size, size);
+        let b: Matrix<f64> = Matrix::new_random(size, size);
+
+        let (naive, blocked) = time_multiply(&a.map(|&x| x as f32), &b.map(|&x| x as f32));
+        println!(
+            "{size}x{size} f32's: naive {naive:?}, blocked {blocked:?} ({:.1}x speedup)",
+            naive.as_secs_f64() / blocked.as_secs_f64()
+        );
+
+        let (naive, blocked) = time_multiply(&a, &b);
+        println!(
+            "{size}x{size} f64's: naive {naive:?}, blocked {blocked:?} ({:.1}x speedup)",
+            naive.as_secs_f64() / blocked.as_secs_f64()
+        );
+    }
+}
+
+/// Number of timed rounds per strassen measurement, the median of which gets reported
+const STRASSEN_RUNS: usize = 7;
+
+/// Measured with `cargo run --release` (from `benchmarks/`, rustc 1.95) on a
+/// 1 vCPU Intel Xeon VM (x86_64-unknown-linux-gnu). Each cell is the median of 7
+/// interleaved rounds after a warm up, from 2 runs of the binary (run 1 / run 2),
+/// as speedups over the blocked kernel (f64's):
+///
+/// | size | blocked         | cutoff 64     | cutoff 128    | cutoff 256    | cutoff 512    |
+/// |------|-----------------|---------------|---------------|---------------|---------------|
+/// | 512  | 40.1ms / 38.0ms | 1.00x / 1.05x | 1.00x / 1.19x | 1.12x / 1.15x | -             |
+/// | 1024 | 371ms / 352ms   | 1.02x / 1.12x | 1.05x / 1.14x | 1.15x / 1.22x | 0.95x / 1.12x |
+/// | 2048 | 3.04s / 2.77s   | 1.13x / 1.28x | 1.07x / 1.41x | 1.23x / 1.33x | 1.35x / 1.35x |
+///
+/// A cutoff of 512 never recurses on a 512x512 matrix, so it isn't timed there.
+/// 256 is the only cutoff ahead of the blocked kernel at every size in both runs,
+/// and the fastest at 1024. At 2048 the gaps between 128, 256 and 512 are within
+/// the run to run spread, so `Matrix::STRASSEN_CUTOFF` stays 256.
+fn bench_strassen_matrix_multiplication() {
+    println!("Benchmarking strassen matrix multiplication against the blocked kernel...");
+
+    for size in [512, 1024, 2048] {
+        let a: Matrix<f64> = Matrix::new_random(size, size);
+        let b: Matrix<f64> = Matrix::new_random(size, size);
+
+        // None is the blocked kernel, a cutoff at or above the size never recurses
+        // so it would just time the blocked kernel again
+        let variants: Vec<Option<usize>> = std::iter::once(None)
+            .chain(
+                [64, 128, 256, 512]
+                    .into_iter()
+                    .filter(|&cutoff| cutoff < size)
+                    .map(Some),
+            )
+            .collect();
+        let time = |variant: Option<usize>| {
+            let start = Instant::now();
+            black_box(match variant {
+                None => a.multiply(&b).ok_or(()),
+                Some(cutoff) => a.multiply_strassen_with_cutoff(&b, cutoff).map_err(|_| ()),
+            })
+            .expect(
+                "Matrix Multiplication To Work. If you're reading this open an issue:
+                https://github.com/antonio-hickey/matrix-oxide/issues",
+            );
+            start.elapsed()
+        };
+
+        // Warm up once, then time every variant once per round, so slow
+        // drift on the machine hits them all alike
+        variants.iter().for_each(|&variant| {
+            time(variant);
+        });
+        let mut durations = vec![Vec::with_capacity(STRASSEN_RUNS); variants.len()];
+        (0..STRASSEN_RUNS).for_each(|_| {
+            variants
+                .iter()
+                .zip(&mut durations)
+                .for_each(|(&variant, runs)| runs.push(time(variant)));
+        });
+        let medians: Vec<Duration> = durations
+            .into_iter()
+            .map(|mut runs| {
+                runs.sort();
+                runs[STRASSEN_RUNS / 2]
+            })
+            .collect();
+
+        let blocked = medians[0];
+        println!("{size}x{size} f64's: blocked {blocked:?}");
+        variants
+            .iter()
+            .zip(&medians)
+            .skip(1)
+            .for_each(|(variant, strassen)| {
+                println!(
+                    "{size}x{size} f64's: strassen (cutoff {}) {strassen:?} ({:.2}x speedup)",
+                    variant.expect("only the first variant is blocked"),
+                    blocked.as_secs_f64() / strassen.as_secs_f64()
+                );
+            });
+    }
+}
+
+/// A dense layer forward pass (`softmax(relu(x * w + bias))` and the transpose
+/// the backward pass needs), once allocating every intermediate and once
+/// reusing preallocated buffers with the in place/out parameter variants.
+fn bench_inplace_training_step() {
+    println!("Benchmarking a training step with and without preallocated buffers...");
+
+    let x: Matrix<f64> = Matrix::new_random(64, 256);
+    let w: Matrix<f64> = Matrix::new_random(256, 128);
+    let bias: Matrix<f64> = Matrix::new_random(64, 128);
+    let iterations = 100;
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    (0..iterations).for_each(|_| {
+        let hidden = x.multiply(&w).expect("inner dimensions match");
+        let hidden = hidden.checked_add(&bias).expect("same shape");
+        let activated = hidden.relu().softmax();
+        let _transposed = activated.transpose();
+    });
+    let duration = start.elapsed();
+    println!(
+        "Allocating: {iterations} steps in {duration:?}, {} allocations per step",
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / iterations
+    );
+
+    let mut hidden: Matrix<f64> = Matrix::new(64, 128);
+    let mut transposed: Matrix<f64> = Matrix::new(128, 64);
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    (0..iterations).for_each(|_| {
+        x.multiply_into(&w, &mut hidden).expect("shapes match");
+        hidden.checked_add_assign(&bias).expect("same shape");
+        hidden.relu_inplace();
+        hidden.softmax_inplace();
+        hidden
+            .transpose_into(&mut transposed)
+            .expect("shapes match");
+    });
+    let duration = start.elapsed();
+    println!(
+        "Preallocated: {iterations} steps in {duration:?}, {} allocations per step",
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / iterations
+    );
+}
+
 fn bench_matrix_inversion() {
     println!("Benchmarking matrix inversion...");
 
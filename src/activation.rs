@@ -1,4 +1,5 @@
-use crate::Matrix;
+use crate::parallel::{self, MIN_PARALLEL_LEN};
+use crate::{Layout, Matrix, MatrixError};
 use std::f64::consts::PI;
 use std::ops::{Add, Mul};
 
@@ -11,17 +12,25 @@ where
     where
         T: PartialOrd + Default + Copy,
     {
-        let data: Vec<T> = self
-            .data
-            .iter()
-            .map(|&x| if x < T::default() { T::default() } else { x })
-            .collect();
+        self.map(|&x| if x < T::default() { T::default() } else { x })
+    }
 
-        Matrix {
-            data,
-            row_size: self.row_size,
-            col_size: self.col_size,
-        }
+    /// Apply the ReLU activation function onto a `Matrix`, splitting large
+    /// matrices over multiple threads with the `parallel` feature.
+    pub fn par_relu(&self) -> Matrix<T>
+    where
+        T: Send + Sync,
+    {
+        self.par_map(|&x| if x < T::default() { T::default() } else { x })
+    }
+
+    /// Apply the ReLU activation function onto a `Matrix` in place (without allocating)
+    pub fn relu_inplace(&mut self) {
+        self.map_inplace(|x| {
+            if *x < T::default() {
+                *x = T::default()
+            }
+        });
     }
 
     /// Apply the Leaky ReLU activation function onto a `Matrix`
@@ -29,17 +38,16 @@ where
     where
         T: PartialOrd + Default + Copy + Mul<Output = T>,
     {
-        let data: Vec<T> = self
-            .data
-            .iter()
-            .map(|&x| if x < T::default() { x * alpha } else { x })
-            .collect();
+        self.map(|&x| if x < T::default() { x * alpha } else { x })
+    }
 
-        Matrix {
-            data,
-            row_size: self.row_size,
-            col_size: self.col_size,
-        }
+    /// Apply the Leaky ReLU activation function onto a `Matrix`, splitting large
+    /// matrices over multiple threads with the `parallel` feature.
+    pub fn par_leaky_relu(&self, alpha: T) -> Matrix<T>
+    where
+        T: Send + Sync,
+    {
+        self.par_map(|&x| if x < T::default() { x * alpha } else { x })
     }
 
     /// Apply backward pass for the ReLU activation function onto a `Matrix`
@@ -47,23 +55,13 @@ where
     where
         T: Copy + PartialOrd + Default + Add<T, Output = T> + Mul<T, Output = T> + From<u8>,
     {
-        let data: Vec<T> = self
-            .data
-            .iter()
-            .map(|&x| {
-                if x >= T::default() {
-                    T::default() + T::from(1u8)
-                } else {
-                    T::default()
-                }
-            })
-            .collect();
-
-        Matrix {
-            data,
-            row_size: self.row_size,
-            col_size: self.col_size,
-        }
+        self.map(|&x| {
+            if x >= T::default() {
+                T::default() + T::from(1u8)
+            } else {
+                T::default()
+            }
+        })
     }
 
     /// Apply the GeLU activation function onto a `Matrix`
@@ -72,72 +70,202 @@ where
     where
         T: Copy + PartialOrd + Default + From<f64> + Into<f64>,
     {
-        let data: Vec<T> = self
-            .data
-            .iter()
-            .map(|&x| {
-                let x_f64: f64 = x.into();
-                let x_gelu = 0.5
-                    * x_f64
-                    * (1.0 + ((2.0 / PI).sqrt() * (x_f64 + 0.04715 * x_f64.powi(3))).tanh());
-                T::from(x_gelu)
-            })
-            .collect();
+        self.map(|&x| T::from(gelu(x.into())))
+    }
 
-        Matrix {
-            data,
-            row_size: self.row_size,
-            col_size: self.col_size,
-        }
+    /// Apply the GeLU activation function onto a `Matrix`, splitting large
+    /// matrices over multiple threads with the `parallel` feature.
+    pub fn par_gelu(&self) -> Matrix<T>
+    where
+        T: From<f64> + Into<f64> + Send + Sync,
+    {
+        self.par_map(|&x| T::from(gelu(x.into())))
     }
 
     /// Apply the softmax activation function onto a `Matrix`.
     ///
     /// NOTE: This is a row wise softmax, if you want to run a column
-    /// wise softmax simply transpose or restride the `Matrix` so the
-    /// desired axis is contiguous, then call `Matrix::softmax`.
+    /// wise softmax call `Matrix::into_transpose` first (which is O(1)).
+    /// Any layout works, though row major reads each row contiguously.
+    ///
+    /// NOTE: Panics if the row/column sizes are inconsistent with the
+    /// data length, use `Matrix::try_softmax` to handle that as an error.
     pub fn softmax(&self) -> Matrix<f64>
     where
         T: Copy + Into<f64> + From<f64>,
     {
-        assert_eq!(
-            self.row_size * self.col_size,
-            self.data.len(),
-            "row/column sizes inconsistent with data length"
-        );
+        self.try_softmax().unwrap_or_else(|err| panic!("{err}"))
+    }
 
-        let data: Vec<f64> = self
-            .data
-            .chunks(self.col_size)
-            .flat_map(|row| {
-                let max = row
-                    .iter()
-                    .copied()
-                    .map(Into::<f64>::into)
-                    .fold(f64::NEG_INFINITY, f64::max);
-
-                let denominator: f64 = row
-                    .iter()
-                    .copied()
-                    .map(Into::<f64>::into)
-                    .map(|v| (v - max).exp())
-                    .sum();
-
-                row.iter()
-                    .copied()
-                    .map(Into::<f64>::into)
-                    .map(move |v| ((v - max).exp()) / denominator)
-            })
-            .collect();
+    /// Apply the (row wise) softmax activation function onto a `Matrix`,
+    /// returning an error if the row/column sizes are inconsistent with the data length.
+    pub fn try_softmax(&self) -> Result<Matrix<f64>, MatrixError>
+    where
+        T: Copy + Into<f64> + From<f64>,
+    {
+        if self.row_size * self.col_size != self.data.len() {
+            return Err(MatrixError::InvalidParameter(
+                "row/column sizes inconsistent with data length".to_string(),
+            ));
+        }
+
+        Ok(Matrix {
+            data: softmax_rows(&self.row_major_data(), self.col_size),
+            row_size: self.row_size,
+            col_size: self.col_size,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Apply the (row wise) softmax activation function onto a `Matrix` in place
+    /// (without allocating), keeping its layout.
+    ///
+    /// NOTE: Panics if the row/column sizes are inconsistent with the
+    /// data length, use `Matrix::try_softmax_inplace` to handle that as an error.
+    pub fn softmax_inplace(&mut self)
+    where
+        T: Copy + Into<f64> + From<f64>,
+    {
+        self.try_softmax_inplace()
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Apply the (row wise) softmax activation function onto a `Matrix` in place,
+    /// returning an error if the row/column sizes are inconsistent with the data length.
+    pub fn try_softmax_inplace(&mut self) -> Result<(), MatrixError>
+    where
+        T: Copy + Into<f64> + From<f64>,
+    {
+        if self.row_size * self.col_size != self.data.len() {
+            return Err(MatrixError::InvalidParameter(
+                "row/column sizes inconsistent with data length".to_string(),
+            ));
+        }
+
+        let (rows, cols) = self.shape();
+        match self.layout {
+            Layout::RowMajor => self
+                .data
+                .chunks_mut(cols.max(1))
+                .for_each(|row| softmax_lane(row, 0..row.len())),
+            Layout::ColumnMajor => (0..rows)
+                .for_each(|row| softmax_lane(&mut self.data, (row..).step_by(rows).take(cols))),
+        }
+
+        Ok(())
+    }
+}
+impl<T> Matrix<T>
+where
+    T: Copy + Into<f64> + From<f64> + Send + Sync,
+{
+    /// Apply the (row wise) softmax activation function onto a `Matrix`,
+    /// splitting the rows over multiple threads with the `parallel` feature.
+    ///
+    /// NOTE: Panics if the row/column sizes are inconsistent with the
+    /// data length, like `Matrix::softmax`.
+    pub fn par_softmax(&self) -> Matrix<f64> {
+        if self.row_size * self.col_size != self.data.len() {
+            let err = MatrixError::InvalidParameter(
+                "row/column sizes inconsistent with data length".to_string(),
+            );
+            panic!("{err}");
+        }
+
+        // Rows are independent, so large matrices get whole rows split over threads
+        let cols = self.col_size;
+        let chunks =
+            parallel::map_chunks(&self.row_major_data(), MIN_PARALLEL_LEN, cols, |_, rows| {
+                softmax_rows(rows, cols)
+            });
 
         Matrix {
-            data,
+            data: parallel::concat(chunks),
             row_size: self.row_size,
             col_size: self.col_size,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Apply the (row wise) softmax activation function onto a `Matrix` in place,
+    /// splitting the rows of a row major `Matrix` over multiple threads with the `parallel` feature.
+    ///
+    /// NOTE: Panics if the row/column sizes are inconsistent with the
+    /// data length, like `Matrix::softmax_inplace`.
+    pub fn par_softmax_inplace(&mut self) {
+        if self.row_size * self.col_size != self.data.len() {
+            let err = MatrixError::InvalidParameter(
+                "row/column sizes inconsistent with data length".to_string(),
+            );
+            panic!("{err}");
+        }
+
+        let (rows, cols) = self.shape();
+        match self.layout {
+            // Rows are contiguous and independent, so large matrices get whole rows split over threads
+            Layout::RowMajor => {
+                let cols = cols.max(1);
+                parallel::for_each_chunk_mut(&mut self.data, MIN_PARALLEL_LEN, cols, |_, chunk| {
+                    chunk
+                        .chunks_mut(cols)
+                        .for_each(|row| softmax_lane(row, 0..row.len()))
+                });
+            }
+            Layout::ColumnMajor => (0..rows)
+                .for_each(|row| softmax_lane(&mut self.data, (row..).step_by(rows).take(cols))),
         }
     }
 }
 
+/// GeLU (tanh approximation) of a single value
+fn gelu(x: f64) -> f64 {
+    0.5 * x * (1.0 + ((2.0 / PI).sqrt() * (x + 0.04715 * x.powi(3))).tanh())
+}
+
+/// Softmax every row of the row major `data`, `cols` elements at a time
+fn softmax_rows<T>(data: &[T], cols: usize) -> Vec<f64>
+where
+    T: Copy + Into<f64>,
+{
+    data.chunks(cols.max(1))
+        .flat_map(|row| {
+            let max = row
+                .iter()
+                .copied()
+                .map(Into::<f64>::into)
+                .fold(f64::NEG_INFINITY, f64::max);
+
+            let denominator: f64 = row
+                .iter()
+                .copied()
+                .map(Into::<f64>::into)
+                .map(|v| (v - max).exp())
+                .sum();
+
+            row.iter()
+                .copied()
+                .map(Into::<f64>::into)
+                .map(move |v| ((v - max).exp()) / denominator)
+        })
+        .collect()
+}
+
+/// Softmax the elements of `data` at the indexes in `lane`
+fn softmax_lane<T, I>(data: &mut [T], lane: I)
+where
+    T: Copy + Into<f64> + From<f64>,
+    I: Iterator<Item = usize> + Clone,
+{
+    let max = lane
+        .clone()
+        .map(|idx| data[idx].into())
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let denominator: f64 = lane.clone().map(|idx| (data[idx].into() - max).exp()).sum();
+
+    lane.for_each(|idx| data[idx] = T::from((data[idx].into() - max).exp() / denominator));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -149,6 +277,7 @@ mod tests {
             data: vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0, 9.0],
             row_size: 3,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
 
         let expected = vec![1.0, 0.0, 3.0, 0.0, 5.0, 0.0, 7.0, 0.0, 9.0];
@@ -157,6 +286,21 @@ mod tests {
         assert_eq!(result.data, expected);
     }
 
+    #[test]
+    fn test_relu_inplace() {
+        let mut matrix = Matrix {
+            data: vec![1.0, -2.0, 3.0, -4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let expected = matrix.relu();
+        matrix.relu_inplace();
+
+        assert_eq!(matrix.data, expected.data);
+    }
+
     #[test]
     /// Verify each element is correctly Leaky ReLU'd
     fn test_leaky_relu() {
@@ -164,6 +308,7 @@ mod tests {
             data: vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0, 9.0],
             row_size: 3,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
 
         let alpha = 0.1;
@@ -191,6 +336,7 @@ mod tests {
             data: vec![1, -2, 3, -4, 5, -6, 7, -8, 9],
             row_size: 3,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
 
         let expected = vec![1, 0, 1, 0, 1, 0, 1, 0, 1];
@@ -206,6 +352,7 @@ mod tests {
             data: vec![1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0, -8.0, 9.0],
             row_size: 3,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
 
         let expected = [
@@ -234,6 +381,7 @@ mod tests {
             data: vec![1.0, 2.0, 3.0, 1.0, 0.0, 1.0],
             row_size: 2,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
 
         let p_values = x.softmax();
@@ -255,6 +403,7 @@ mod tests {
             ],
             row_size: 4,
             col_size: 5,
+            layout: Layout::RowMajor,
         };
 
         let p_values = x.softmax();
@@ -266,4 +415,160 @@ mod tests {
             assert!((row_sum - 1.0).abs() < 1e-6, "row {r} sums to {row_sum}");
         })
     }
+
+    #[test]
+    /// Verify an inconsistent shape is reported instead of panicking
+    fn test_try_softmax_inconsistent_shape() {
+        let x = Matrix {
+            data: vec![1.0, 2.0, 3.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert!(matches!(
+            x.try_softmax(),
+            Err(MatrixError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "row/column sizes inconsistent with data length")]
+    fn test_softmax_inconsistent_shape_panics() {
+        let x = Matrix {
+            data: vec![1.0, 2.0, 3.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        x.softmax();
+    }
+
+    #[test]
+    fn test_try_softmax_empty() {
+        let x = Matrix::<f64>::new(0, 0);
+        assert!(x.try_softmax().unwrap().data.is_empty());
+    }
+
+    #[test]
+    /// Verify softmax over a transposed `Matrix` normalizes the original columns
+    fn test_softmax_transposed() {
+        let matrix = Matrix {
+            data: vec![1.0, 0.0, 2.0, 0.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.into_transpose().softmax();
+        let e = std::f64::consts::E;
+
+        assert_eq!(result.layout, Layout::RowMajor);
+        assert!((result.data[0] - e / (e + e * e)).abs() < 1e-12);
+        assert!((result.data[2] - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    /// Verify the in place softmax matches `Matrix::softmax` for either layout
+    fn test_softmax_inplace() {
+        let data = vec![1.0, 2.0, 3.0, 1.0, 0.0, 1.0];
+        let mut matrix = Matrix {
+            data: data.clone(),
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        let mut column_major = Matrix {
+            data,
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        }
+        .into_layout(Layout::ColumnMajor);
+        let expected = matrix.softmax();
+
+        matrix.softmax_inplace();
+        column_major.softmax_inplace();
+
+        assert_eq!(matrix.data, expected.data);
+        assert_eq!(column_major.layout, Layout::ColumnMajor);
+        (0..2).for_each(|row| {
+            assert_eq!(column_major.try_get_row(row), expected.try_get_row(row));
+        });
+    }
+
+    #[test]
+    /// Verify the threaded element wise activations match the sequential ones
+    fn test_par_relu_leaky_relu_gelu() {
+        let matrix = Matrix {
+            data: (0..400 * 200)
+                .map(|x| (x % 17) as f64 / 4.0 - 2.0)
+                .collect(),
+            row_size: 400,
+            col_size: 200,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!(matrix.par_relu().data, matrix.relu().data);
+        assert_eq!(matrix.par_leaky_relu(0.1).data, matrix.leaky_relu(0.1).data);
+        assert_eq!(matrix.par_gelu().data, matrix.gelu().data);
+
+        let column_major = matrix.par_map(|&x| x).into_layout(Layout::ColumnMajor);
+        assert_eq!(
+            column_major.par_relu().into_layout(Layout::RowMajor).data,
+            matrix.relu().data
+        );
+    }
+
+    #[test]
+    /// Verify the threaded softmax variants match the sequential ones
+    fn test_par_softmax() {
+        let matrix = Matrix {
+            data: (0..400 * 100).map(|x| (x % 13) as f64 / 4.0).collect(),
+            row_size: 400,
+            col_size: 100,
+            layout: Layout::RowMajor,
+        };
+        let expected = matrix.softmax();
+        let mut inplace = matrix.par_map(|&x| x);
+        let mut column_major = matrix.par_map(|&x| x).into_layout(Layout::ColumnMajor);
+
+        inplace.par_softmax_inplace();
+        column_major.par_softmax_inplace();
+
+        assert_eq!(matrix.par_softmax().data, expected.data);
+        assert_eq!(inplace.data, expected.data);
+        assert_eq!(
+            column_major.into_layout(Layout::RowMajor).data,
+            expected.data
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "row/column sizes inconsistent with data length")]
+    fn test_par_softmax_inconsistent_shape_panics() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        matrix.par_softmax();
+    }
+
+    #[test]
+    fn test_try_softmax_inplace_inconsistent_shape() {
+        let mut x = Matrix {
+            data: vec![1.0, 2.0, 3.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert!(matches!(
+            x.try_softmax_inplace(),
+            Err(MatrixError::InvalidParameter(_))
+        ));
+    }
 }
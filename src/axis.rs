@@ -0,0 +1,576 @@
+use crate::parallel::{self, MIN_PARALLEL_LEN};
+use crate::{Layout, Matrix, MatrixError};
+use std::ops::Add;
+
+/// Direction to work along a `Matrix`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    /// Work along each row, giving one result per row
+    Row,
+    /// Work along each column, giving one result per column
+    Column,
+}
+
+/// Fold `lanes` back to back runs of `lane_size` elements, one value per run
+fn fold_contiguous<T, A, F>(data: &[T], lanes: usize, lane_size: usize, init: A, f: F) -> Vec<A>
+where
+    A: Clone,
+    F: Fn(A, &T) -> A,
+{
+    (0..lanes)
+        .map(|lane| {
+            data[lane * lane_size..(lane + 1) * lane_size]
+                .iter()
+                .fold(init.clone(), &f)
+        })
+        .collect()
+}
+
+/// Fold `lanes` back to back runs of `lane_size` elements, one value per run,
+/// splitting the runs over multiple threads with the `parallel` feature.
+fn par_fold_contiguous<T, A, F>(data: &[T], lanes: usize, lane_size: usize, init: A, f: F) -> Vec<A>
+where
+    T: Sync,
+    A: Clone + Send + Sync,
+    F: Fn(A, &T) -> A + Sync,
+{
+    // Empty runs can't be chunked, but every one of them folds to `init`
+    if lane_size == 0 {
+        return vec![init; lanes];
+    }
+
+    let chunks = parallel::map_chunks(data, MIN_PARALLEL_LEN, lane_size, |_, runs| {
+        runs.chunks(lane_size)
+            .map(|run| run.iter().fold(init.clone(), &f))
+            .collect::<Vec<A>>()
+    });
+    parallel::concat(chunks)
+}
+
+/// Fold `lanes` interleaved lanes of `lane_size` elements, where `data` holds
+/// the next element of every lane one after another.
+fn fold_interleaved<T, A, F>(data: &[T], lanes: usize, lane_size: usize, init: A, f: F) -> Vec<A>
+where
+    A: Clone,
+    F: Fn(A, &T) -> A,
+{
+    // Walk the data in memory order, updating every lanes accumulator as we go
+    (0..lane_size).fold(vec![init; lanes], |acc, step| {
+        acc.into_iter()
+            .zip(&data[step * lanes..(step + 1) * lanes])
+            .map(|(acc, value)| f(acc, value))
+            .collect()
+    })
+}
+
+impl<T> Matrix<T> {
+    /// Fold every row of the `Matrix` into a single value, starting each row from `init`
+    pub fn fold_rows<A, F>(&self, init: A, f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: Fn(A, &T) -> A,
+    {
+        let (rows, cols) = self.shape();
+        match self.layout {
+            Layout::RowMajor => fold_contiguous(&self.data, rows, cols, init, f),
+            Layout::ColumnMajor => fold_interleaved(&self.data, rows, cols, init, f),
+        }
+    }
+
+    /// Fold every column of the `Matrix` into a single value, starting each column from `init`
+    pub fn fold_cols<A, F>(&self, init: A, f: F) -> Vec<A>
+    where
+        A: Clone,
+        F: Fn(A, &T) -> A,
+    {
+        let (rows, cols) = self.shape();
+        match self.layout {
+            Layout::RowMajor => fold_interleaved(&self.data, cols, rows, init, f),
+            Layout::ColumnMajor => fold_contiguous(&self.data, cols, rows, init, f),
+        }
+    }
+
+    /// Fold every row of the `Matrix` into a single value, starting each row from `init`,
+    /// splitting the rows over multiple threads with the `parallel` feature.
+    /// NOTE: Only a row major `Matrix` gets split, its rows are stored back to back.
+    pub fn par_fold_rows<A, F>(&self, init: A, f: F) -> Vec<A>
+    where
+        T: Sync,
+        A: Clone + Send + Sync,
+        F: Fn(A, &T) -> A + Sync,
+    {
+        let (rows, cols) = self.shape();
+        match self.layout {
+            Layout::RowMajor => par_fold_contiguous(&self.data, rows, cols, init, f),
+            Layout::ColumnMajor => fold_interleaved(&self.data, rows, cols, init, f),
+        }
+    }
+
+    /// Fold every column of the `Matrix` into a single value, starting each column from `init`,
+    /// splitting the columns over multiple threads with the `parallel` feature.
+    /// NOTE: Only a column major `Matrix` gets split, its columns are stored back to back.
+    pub fn par_fold_cols<A, F>(&self, init: A, f: F) -> Vec<A>
+    where
+        T: Sync,
+        A: Clone + Send + Sync,
+        F: Fn(A, &T) -> A + Sync,
+    {
+        let (rows, cols) = self.shape();
+        match self.layout {
+            Layout::RowMajor => fold_interleaved(&self.data, cols, rows, init, f),
+            Layout::ColumnMajor => par_fold_contiguous(&self.data, cols, rows, init, f),
+        }
+    }
+
+    /// Apply `f` to every row (`Axis::Row`) or column (`Axis::Column`) of the `Matrix`,
+    /// where `f` maps a lane to a new lane of K values.
+    ///
+    /// The lanes are stacked back into a MxK `Matrix` for `Axis::Row`
+    /// or a KxN `Matrix` for `Axis::Column`.
+    ///
+    /// NOTE: Every lane produced by `f` MUST have the same length, else returns an error
+    pub fn apply_along_axis<U, F>(&self, axis: Axis, f: F) -> Result<Matrix<U>, MatrixError>
+    where
+        T: Clone,
+        U: Clone,
+        F: Fn(&[T]) -> Vec<U>,
+    {
+        let (lane_count, lane_size) = match axis {
+            Axis::Row => self.shape(),
+            Axis::Column => (self.col_size, self.row_size),
+        };
+        let contiguous = matches!(
+            (axis, self.layout),
+            (Axis::Row, Layout::RowMajor) | (Axis::Column, Layout::ColumnMajor)
+        );
+
+        // Lanes stored back to back are passed straight through, others are gathered first
+        let lanes: Vec<Vec<U>> = (0..lane_count)
+            .map(|lane| match contiguous {
+                true => f(&self.data[lane * lane_size..(lane + 1) * lane_size]),
+                false => {
+                    let gathered: Vec<T> = (0..lane_size)
+                        .map(|idx| match axis {
+                            Axis::Row => self.offset(lane, idx),
+                            Axis::Column => self.offset(idx, lane),
+                        })
+                        .map(|offset| self.data[offset].clone())
+                        .collect();
+                    f(&gathered)
+                }
+            })
+            .collect();
+
+        // Validity check that every lane has the same length
+        let output_size = lanes.first().map_or(0, Vec::len);
+        if lanes.iter().any(|lane| lane.len() != output_size) {
+            return Err(MatrixError::InvalidParameter(
+                "every lane must map to the same number of values".to_string(),
+            ));
+        }
+
+        Ok(match axis {
+            Axis::Row => Matrix {
+                data: lanes.into_iter().flatten().collect(),
+                row_size: lane_count,
+                col_size: output_size,
+                layout: Layout::RowMajor,
+            },
+            Axis::Column => Matrix {
+                data: (0..output_size)
+                    .flat_map(|row| lanes.iter().map(move |lane| lane[row].clone()))
+                    .collect(),
+                row_size: output_size,
+                col_size: lane_count,
+                layout: Layout::RowMajor,
+            },
+        })
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Fold every lane along `axis` into a single value, shaped into a Mx1
+    /// `Matrix` for `Axis::Row` or a 1xN `Matrix` for `Axis::Column`.
+    fn fold_axis<A, F>(&self, axis: Axis, init: A, f: F) -> Matrix<A>
+    where
+        A: Clone,
+        F: Fn(A, &T) -> A,
+    {
+        match axis {
+            Axis::Row => Matrix {
+                data: self.fold_rows(init, f),
+                row_size: self.row_size,
+                col_size: 1,
+                layout: Layout::RowMajor,
+            },
+            Axis::Column => Matrix {
+                data: self.fold_cols(init, f),
+                row_size: 1,
+                col_size: self.col_size,
+                layout: Layout::RowMajor,
+            },
+        }
+    }
+
+    /// Running (count, mean, sum of squared deviations) of every lane along `axis`
+    /// NOTE: Uses Welford's algorithm, so it's a single numerically stable pass.
+    fn welford(&self, axis: Axis) -> Matrix<(f64, f64, f64)>
+    where
+        T: Copy + Into<f64>,
+    {
+        self.fold_axis(axis, (0.0, 0.0, 0.0), |(count, mean, m2), &x| {
+            let x: f64 = x.into();
+            let count = count + 1.0;
+            let delta = x - mean;
+            let mean = mean + delta / count;
+            (count, mean, m2 + delta * (x - mean))
+        })
+    }
+
+    /// Pick the position and value of the "best" element of every lane along `axis`,
+    /// where `better(a, b)` says if `a` should replace the current best `b`.
+    fn select_along_axis<F>(&self, axis: Axis, better: F) -> Option<Matrix<(usize, T)>>
+    where
+        T: Copy,
+        F: Fn(&T, &T) -> bool,
+    {
+        let selected = self.fold_axis(axis, (0, None), |(idx, best), &x| {
+            let best = match best {
+                Some((_, value)) if !better(&x, &value) => best,
+                _ => Some((idx, x)),
+            };
+            (idx + 1, best)
+        });
+
+        Some(Matrix {
+            data: selected
+                .data
+                .into_iter()
+                .map(|(_, best)| best)
+                .collect::<Option<Vec<(usize, T)>>>()?,
+            row_size: selected.row_size,
+            col_size: selected.col_size,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Sum every row of the `Matrix`, giving one value per row
+    pub fn sum_rows(&self) -> Vec<T>
+    where
+        T: Default + Clone + Add<Output = T>,
+    {
+        self.fold_rows(T::default(), |acc, x| acc + x.clone())
+    }
+
+    /// Sum every column of the `Matrix`, giving one value per column
+    pub fn sum_cols(&self) -> Vec<T>
+    where
+        T: Default + Clone + Add<Output = T>,
+    {
+        self.fold_cols(T::default(), |acc, x| acc + x.clone())
+    }
+
+    /// Compute the mean along `axis`, as a Mx1 `Matrix` for `Axis::Row`
+    /// or a 1xN `Matrix` for `Axis::Column` (so it can be broadcast back).
+    pub fn mean(&self, axis: Axis) -> Matrix<T>
+    where
+        T: Copy + Into<f64> + From<f64>,
+    {
+        self.welford(axis)
+            .map(|&(count, mean, _)| match count > 0.0 {
+                true => T::from(mean),
+                false => T::from(f64::NAN),
+            })
+    }
+
+    /// Compute the (population) variance along `axis`, as a Mx1 `Matrix`
+    /// for `Axis::Row` or a 1xN `Matrix` for `Axis::Column`.
+    /// NOTE: Divides by the lane size N, not N - 1.
+    pub fn variance(&self, axis: Axis) -> Matrix<T>
+    where
+        T: Copy + Into<f64> + From<f64>,
+    {
+        self.welford(axis)
+            .map(|&(count, _, m2)| T::from(m2 / count))
+    }
+
+    /// Compute the (population) standard deviation along `axis`, as a Mx1
+    /// `Matrix` for `Axis::Row` or a 1xN `Matrix` for `Axis::Column`.
+    pub fn std(&self, axis: Axis) -> Matrix<T>
+    where
+        T: Copy + Into<f64> + From<f64>,
+    {
+        self.welford(axis)
+            .map(|&(count, _, m2)| T::from((m2 / count).sqrt()))
+    }
+
+    /// Find the smallest element along `axis`, as a Mx1 `Matrix` for
+    /// `Axis::Row` or a 1xN `Matrix` for `Axis::Column`.
+    /// NOTE: Returns None if the lanes along `axis` are empty.
+    pub fn min(&self, axis: Axis) -> Option<Matrix<T>>
+    where
+        T: Copy + PartialOrd,
+    {
+        Some(self.select_along_axis(axis, |a, b| a < b)?.map(|&(_, x)| x))
+    }
+
+    /// Find the largest element along `axis`, as a Mx1 `Matrix` for
+    /// `Axis::Row` or a 1xN `Matrix` for `Axis::Column`.
+    /// NOTE: Returns None if the lanes along `axis` are empty.
+    pub fn max(&self, axis: Axis) -> Option<Matrix<T>>
+    where
+        T: Copy + PartialOrd,
+    {
+        Some(self.select_along_axis(axis, |a, b| a > b)?.map(|&(_, x)| x))
+    }
+
+    /// Find the position of the smallest element in every lane along `axis`,
+    /// the first one wins on ties.
+    /// NOTE: Returns None if the lanes along `axis` are empty.
+    pub fn argmin(&self, axis: Axis) -> Option<Vec<usize>>
+    where
+        T: Copy + PartialOrd,
+    {
+        let selected = self.select_along_axis(axis, |a, b| a < b)?;
+        Some(selected.data.into_iter().map(|(idx, _)| idx).collect())
+    }
+
+    /// Find the position of the largest element in every lane along `axis`,
+    /// the first one wins on ties (e.g. the predicted class of every row of logits).
+    /// NOTE: Returns None if the lanes along `axis` are empty.
+    pub fn argmax(&self, axis: Axis) -> Option<Vec<usize>>
+    where
+        T: Copy + PartialOrd,
+    {
+        let selected = self.select_along_axis(axis, |a, b| a > b)?;
+        Some(selected.data.into_iter().map(|(idx, _)| idx).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix_2x3() -> Matrix<i32> {
+        Matrix {
+            data: vec![1, 2, 3, 4, 5, 6],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    #[test]
+    fn test_fold_rows() {
+        let result = matrix_2x3().fold_rows(0, |acc, &x| acc + x);
+        assert_eq!(result, vec![6, 15]);
+    }
+
+    #[test]
+    fn test_fold_cols() {
+        let result = matrix_2x3().fold_cols(1, |acc, &x| acc * x);
+        assert_eq!(result, vec![4, 10, 18]);
+    }
+
+    #[test]
+    /// Verify the threaded folds match the sequential ones for either layout
+    fn test_par_fold_rows_and_cols() {
+        let matrix = Matrix {
+            data: (0..500 * 200).map(|x| x % 97).collect::<Vec<i64>>(),
+            row_size: 500,
+            col_size: 200,
+            layout: Layout::RowMajor,
+        };
+        let sum = |acc: i64, &x: &i64| acc + x;
+
+        assert_eq!(matrix.par_fold_rows(0, sum), matrix.fold_rows(0, sum));
+        assert_eq!(matrix.par_fold_cols(0, sum), matrix.fold_cols(0, sum));
+
+        let matrix = matrix.into_layout(Layout::ColumnMajor);
+        assert_eq!(matrix.par_fold_rows(0, sum), matrix.fold_rows(0, sum));
+        assert_eq!(matrix.par_fold_cols(0, sum), matrix.fold_cols(0, sum));
+    }
+
+    #[test]
+    fn test_fold_empty_matrix() {
+        let matrix = Matrix::<i32>::new(0, 0);
+
+        assert!(matrix.fold_rows(0, |acc, &x| acc + x).is_empty());
+        assert!(matrix.fold_cols(0, |acc, &x| acc + x).is_empty());
+        assert_eq!(
+            Matrix::<i32>::new(2, 0).fold_rows(7, |acc, &x| acc + x),
+            vec![7, 7]
+        );
+        assert_eq!(
+            Matrix::<i32>::new(0, 2).fold_cols(7, |acc, &x| acc + x),
+            vec![7, 7]
+        );
+    }
+
+    #[test]
+    /// Verify every (empty) row of a Mx0 `Matrix` still gets a lane
+    fn test_apply_along_axis_empty_rows() {
+        let result = Matrix::<i32>::new(2, 0)
+            .apply_along_axis(Axis::Row, |row| vec![row.len() as i32])
+            .unwrap();
+
+        assert_eq!(result.data, vec![0, 0]);
+        assert_eq!((result.row_size, result.col_size), (2, 1));
+    }
+
+    #[test]
+    fn test_apply_along_rows() {
+        let result = matrix_2x3()
+            .apply_along_axis(Axis::Row, |row| row.iter().rev().cloned().collect())
+            .unwrap();
+
+        assert_eq!(result.shape(), (2, 3));
+        assert_eq!(result.data, vec![3, 2, 1, 6, 5, 4]);
+    }
+
+    #[test]
+    fn test_apply_along_columns() {
+        let result = matrix_2x3()
+            .apply_along_axis(Axis::Column, |col| vec![col.iter().sum::<i32>()])
+            .unwrap();
+
+        assert_eq!(result.shape(), (1, 3));
+        assert_eq!(result.data, vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn test_apply_along_columns_keeps_layout() {
+        let result = matrix_2x3()
+            .apply_along_axis(Axis::Column, |col| col.iter().map(|x| x * 10).collect())
+            .unwrap();
+
+        assert_eq!(result.shape(), (2, 3));
+        assert_eq!(result.data, vec![10, 20, 30, 40, 50, 60]);
+    }
+
+    #[test]
+    fn test_apply_along_axis_ragged_lanes() {
+        let result = matrix_2x3().apply_along_axis(Axis::Row, |row| vec![0; row[0] as usize]);
+
+        assert!(matches!(result, Err(MatrixError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn test_sum_rows_and_cols() {
+        assert_eq!(matrix_2x3().sum_rows(), vec![6, 15]);
+        assert_eq!(matrix_2x3().sum_cols(), vec![5, 7, 9]);
+    }
+
+    #[test]
+    fn test_mean() {
+        let matrix = matrix_2x3().map(|&x| x as f64);
+
+        let row_mean = matrix.mean(Axis::Row);
+        let col_mean = matrix.mean(Axis::Column);
+
+        assert_eq!(row_mean.shape(), (2, 1));
+        assert_eq!(row_mean.data, vec![2.0, 5.0]);
+        assert_eq!(col_mean.shape(), (1, 3));
+        assert_eq!(col_mean.data, vec![2.5, 3.5, 4.5]);
+    }
+
+    #[test]
+    fn test_variance_and_std() {
+        let matrix = Matrix {
+            data: vec![2.0_f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0],
+            row_size: 1,
+            col_size: 8,
+            layout: Layout::RowMajor,
+        };
+
+        assert!((matrix.variance(Axis::Row).data[0] - 4.0).abs() < 1e-12);
+        assert!((matrix.std(Axis::Row).data[0] - 2.0).abs() < 1e-12);
+        assert_eq!(matrix.variance(Axis::Column).data, vec![0.0; 8]);
+    }
+
+    #[test]
+    /// Verify the column statistics broadcast back to normalize a batch
+    fn test_batch_normalize() {
+        let batch = Matrix {
+            data: vec![1.0, 10.0, 3.0, 30.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let normalized = batch
+            .broadcast_sub(&batch.mean(Axis::Column))
+            .unwrap()
+            .elementwise_divide(&batch.std(Axis::Column))
+            .unwrap();
+
+        assert_eq!(normalized.data, vec![-1.0, -1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let matrix = Matrix {
+            data: vec![3, -1, 7, 0, 9, -4],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!(matrix.min(Axis::Row).unwrap().data, vec![-1, -4]);
+        assert_eq!(matrix.max(Axis::Row).unwrap().data, vec![7, 9]);
+        assert_eq!(matrix.min(Axis::Column).unwrap().data, vec![0, -1, -4]);
+        assert_eq!(matrix.max(Axis::Column).unwrap().shape(), (1, 3));
+    }
+
+    #[test]
+    /// Verify argmax picks the predicted class of every row of logits
+    fn test_argmin_argmax() {
+        let logits = Matrix {
+            data: vec![0.1, 2.5, 0.3, 1.0, 1.0, -2.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!(logits.argmax(Axis::Row), Some(vec![1, 0]));
+        assert_eq!(logits.argmin(Axis::Row), Some(vec![0, 2]));
+        assert_eq!(logits.argmax(Axis::Column), Some(vec![1, 0, 0]));
+        assert_eq!(logits.argmin(Axis::Column), Some(vec![0, 1, 1]));
+    }
+
+    #[test]
+    fn test_reductions_over_empty_lanes() {
+        let matrix = Matrix::<f64>::new(2, 0);
+
+        assert!(matrix.max(Axis::Row).is_none());
+        assert!(matrix.argmin(Axis::Row).is_none());
+        assert_eq!(matrix.max(Axis::Column).unwrap().shape(), (1, 0));
+        assert!(matrix.mean(Axis::Row).data.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    /// Verify folds and reductions agree for a column major `Matrix`
+    fn test_column_major_reductions() {
+        let column_major = matrix_2x3().into_layout(Layout::ColumnMajor);
+
+        assert_eq!(column_major.sum_rows(), vec![6, 15]);
+        assert_eq!(column_major.sum_cols(), vec![5, 7, 9]);
+        assert_eq!(column_major.argmax(Axis::Row), Some(vec![2, 2]));
+        assert_eq!(column_major.min(Axis::Column).unwrap().data, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_apply_along_axis_column_major() {
+        let column_major = matrix_2x3().into_layout(Layout::ColumnMajor);
+
+        let rows = column_major
+            .apply_along_axis(Axis::Row, |row| row.iter().rev().cloned().collect())
+            .unwrap();
+        let cols = column_major
+            .apply_along_axis(Axis::Column, |col| vec![col[1] - col[0]])
+            .unwrap();
+
+        assert_eq!(rows.data, vec![3, 2, 1, 6, 5, 4]);
+        assert_eq!(cols.data, vec![3, 3, 3]);
+    }
+}
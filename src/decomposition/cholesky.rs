@@ -0,0 +1,360 @@
+use crate::matrix::Abs;
+use crate::{Layout, Matrix, MatrixError};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Cholesky decomposition of a symmetric positive-definite `Matrix`, so that `A = LL^T`.
+pub struct Cholesky<T> {
+    /// Lower triangular factor
+    pub l: Matrix<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Compute the Cholesky decomposition of a `Matrix`
+    /// NOTE: Only computable for symmetric positive-definite matrices,
+    /// returns None for any other `Matrix`.
+    pub fn cholesky(&self) -> Option<Cholesky<T>> {
+        // Validity check that it's a square matrix
+        if self.row_size != self.col_size {
+            return None;
+        }
+
+        let n = self.row_size;
+        let a = |row: usize, col: usize| self.data[self.offset(row, col)];
+
+        // Tolerance relative to the largest entry, so scaling the matrix doesn't change the result
+        let scale = self
+            .data
+            .iter()
+            .fold(T::default(), |acc, &x| match x.abs() > acc {
+                true => x.abs(),
+                false => acc,
+            });
+        let epsilon = T::from(1e-10) * scale;
+
+        // Validity check that it's a symmetric matrix
+        let is_symmetric =
+            (0..n).all(|row| (row + 1..n).all(|col| (a(row, col) - a(col, row)).abs() <= epsilon));
+        if !is_symmetric {
+            return None;
+        }
+
+        let mut l = vec![T::default(); n * n];
+        for i in 0..n {
+            for j in 0..=i {
+                let sum = (0..j).fold(a(i, j), |acc, k| acc - l[i * n + k] * l[j * n + k]);
+
+                if i == j {
+                    // A non positive pivot means the matrix isn't positive-definite
+                    if sum <= epsilon {
+                        return None;
+                    }
+                    l[i * n + j] = T::from(sum.into().sqrt());
+                } else {
+                    l[i * n + j] = sum / l[j * n + j];
+                }
+            }
+        }
+
+        Some(Cholesky {
+            l: Matrix {
+                data: l,
+                row_size: n,
+                col_size: n,
+                layout: Layout::RowMajor,
+            },
+        })
+    }
+
+    /// Compute the Cholesky decomposition of a `Matrix`, returning an error
+    /// for a non square or not symmetric positive-definite `Matrix`
+    pub fn try_cholesky(&self) -> Result<Cholesky<T>, MatrixError> {
+        if self.row_size != self.col_size {
+            return Err(MatrixError::NonSquare {
+                shape: self.shape(),
+            });
+        }
+
+        self.cholesky().ok_or(MatrixError::InvalidParameter(
+            "matrix is not symmetric positive-definite".to_string(),
+        ))
+    }
+}
+
+impl<T> Cholesky<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Solve `Ax = b` for `x` using the factored `Matrix` as `A`
+    /// NOTE: The vectors length MUST match the matrix size, else returns None
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        let n = self.l.row_size;
+
+        // Validity check that the vector matches the matrix size
+        if b.len() != n {
+            return None;
+        }
+
+        // Forward substitution (Ly = b)
+        let mut x = b.to_vec();
+        (0..n).for_each(|i| {
+            let sum = (0..i).fold(x[i], |acc, k| acc - self.l.data[i * n + k] * x[k]);
+            x[i] = sum / self.l.data[i * n + i];
+        });
+
+        // Back substitution (L^T x = y)
+        (0..n).rev().for_each(|i| {
+            let sum = (i + 1..n).fold(x[i], |acc, k| acc - self.l.data[k * n + i] * x[k]);
+            x[i] = sum / self.l.data[i * n + i];
+        });
+
+        Some(x)
+    }
+
+    /// Solve `AX = B` for `X` using the factored `Matrix` as `A`, where each
+    /// column of `B` is a separate right hand side.
+    /// NOTE: The row size of `B` MUST match the matrix size, else returns None
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        // Validity check that the right hand sides match the matrix size
+        if b.row_size != self.l.row_size {
+            return None;
+        }
+
+        let columns = (0..b.col_size)
+            .map(|col| self.solve(&b.try_get_column(col)?))
+            .collect::<Option<Vec<Vec<T>>>>()?;
+
+        Some(Matrix {
+            data: Matrix::from_columns(columns).data,
+            row_size: b.row_size,
+            col_size: b.col_size,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Compute the determinant of the factored `Matrix`
+    pub fn determinant(&self) -> T {
+        self.l
+            .get_diagonal()
+            .into_iter()
+            .fold(T::from(1.0), |acc, d| acc * d * d)
+    }
+
+    /// Transform a vector of independent standard normal samples `z` into
+    /// samples correlated by the factored (covariance) `Matrix`, computing `Lz`.
+    /// NOTE: The vectors length MUST match the matrix size, else returns None
+    pub fn correlate(&self, z: &[T]) -> Option<Vec<T>> {
+        let n = self.l.row_size;
+
+        // Validity check that the vector matches the matrix size
+        if z.len() != n {
+            return None;
+        }
+
+        // L is lower triangular, so only the first i + 1 entries contribute
+        let data = (0..n)
+            .map(|i| (0..=i).fold(T::default(), |acc, k| acc + self.l.data[i * n + k] * z[k]))
+            .collect();
+
+        Some(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check if 2 float value's are *ABOUT* equal
+    fn approx_equal(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(&a, &b)| (a - b).abs() < epsilon)
+    }
+
+    fn spd_matrix() -> Matrix<f64> {
+        Matrix {
+            data: vec![4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    #[test]
+    fn test_cholesky_factor() {
+        let cholesky = spd_matrix().cholesky().unwrap();
+
+        let expected = vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0];
+        assert!(approx_equal(&cholesky.l.data, &expected, 1e-12));
+    }
+
+    #[test]
+    /// Verify `A = LL^T` for a gram matrix built from `multiply` and `transpose`
+    fn test_cholesky_reconstructs_gram_matrix() {
+        let x = Matrix {
+            data: vec![1.0, 2.0, 0.5, -1.0, 3.0, 1.0, 0.0, 2.0, 1.5, 1.0, -2.0, 0.5],
+            row_size: 4,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        let gram = x.transpose().multiply(&x).unwrap();
+
+        let cholesky = gram.cholesky().unwrap();
+        let product = cholesky.l.multiply(&cholesky.l.transpose()).unwrap();
+
+        assert!(approx_equal(&product.data, &gram.data, 1e-10));
+    }
+
+    #[test]
+    fn test_cholesky_not_symmetric() {
+        let matrix = Matrix {
+            data: vec![4.0, 1.0, 2.0, 3.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        assert!(matrix.cholesky().is_none());
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 2.0, 1.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        assert!(matrix.cholesky().is_none());
+    }
+
+    #[test]
+    /// Verify the tolerances follow the scale of the matrix
+    fn test_cholesky_scale_invariant() {
+        let tiny = spd_matrix().scalar_multiply(1e-12);
+        let cholesky = tiny.cholesky().unwrap();
+        let expected = [2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0].map(|x| x * 1e-6);
+        assert!(approx_equal(&cholesky.l.data, &expected, 1e-18));
+
+        let identity = Matrix::<f64>::identity(3).scalar_multiply(1e-12);
+        assert!(identity.cholesky().is_some());
+
+        // Off by 1e-4, far above an absolute 1e-10 but a rounding error next to 1e12
+        let huge = Matrix {
+            data: vec![4e12, 1e12 + 1e-4, 1e12, 3e12],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        assert!(huge.cholesky().is_some());
+        assert!(Matrix::<f64>::new(2, 2).cholesky().is_none());
+    }
+
+    #[test]
+    fn test_cholesky_column_major() {
+        let cholesky = spd_matrix()
+            .into_layout(Layout::ColumnMajor)
+            .cholesky()
+            .unwrap();
+
+        let expected = vec![2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0];
+        assert!(approx_equal(&cholesky.l.data, &expected, 1e-12));
+    }
+
+    #[test]
+    fn test_cholesky_non_square() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        assert!(matrix.cholesky().is_none());
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        let matrix = spd_matrix();
+        let b = matrix.vector_multiply(&[1.0, -2.0, 3.0]).unwrap();
+
+        let x = matrix.cholesky().unwrap().solve(&b).unwrap();
+
+        assert!(approx_equal(&x, &[1.0, -2.0, 3.0], 1e-10));
+    }
+
+    #[test]
+    fn test_cholesky_solve_matrix() {
+        let matrix = spd_matrix();
+        let x = matrix.cholesky().unwrap().solve_matrix(&matrix).unwrap();
+
+        assert!(approx_equal(
+            &x.data,
+            &Matrix::<f64>::identity(3).data,
+            1e-10
+        ));
+    }
+
+    #[test]
+    fn test_cholesky_solve_wrong_length() {
+        let cholesky = spd_matrix().cholesky().unwrap();
+        assert!(cholesky.solve(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_cholesky_determinant() {
+        let cholesky = spd_matrix().cholesky().unwrap();
+        assert!((cholesky.determinant() - 36.0).abs() < 1e-10);
+    }
+
+    #[test]
+    /// Verify `Lz` for unit vectors picks out the columns of `L`
+    fn test_cholesky_correlate() {
+        let cholesky = spd_matrix().cholesky().unwrap();
+
+        let result = cholesky.correlate(&[0.0, 1.0, 0.0]).unwrap();
+
+        assert!(approx_equal(&result, &[0.0, 1.0, 5.0], 1e-12));
+        assert!(cholesky.correlate(&[1.0]).is_none());
+    }
+
+    #[test]
+    fn test_try_cholesky_errors() {
+        let non_square = Matrix::<f64>::new(2, 3);
+        let indefinite = Matrix {
+            data: vec![1.0, 2.0, 2.0, 1.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!(
+            non_square.try_cholesky().err(),
+            Some(MatrixError::NonSquare { shape: (2, 3) })
+        );
+        assert!(matches!(
+            indefinite.try_cholesky(),
+            Err(MatrixError::InvalidParameter(_))
+        ));
+        assert!(spd_matrix().try_cholesky().is_ok());
+    }
+}
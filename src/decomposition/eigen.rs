@@ -0,0 +1,952 @@
+use crate::matrix::Abs;
+use crate::numbers::Complex;
+use crate::random::Rng;
+use crate::{Layout, Matrix};
+use std::ops::{Add, Div, Mul, Range, Sub};
+
+/// Maximum number of full Jacobi sweeps before giving up on convergence
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Maximum number of shifted QR iterations spent on a single eigenvalue
+const MAX_QR_ITERATIONS: usize = 30;
+
+/// Number of inverse iteration steps used to refine each eigenvector
+const INVERSE_ITERATIONS: usize = 3;
+
+/// Largest overlap `|v_i^H v_j|` allowed between 2 unit eigenvectors before
+/// they are treated as the same direction
+const MAX_EIGENVECTOR_OVERLAP: f64 = 1.0 - 1e-6;
+
+/// Largest distance between 2 eigenvalues (relative to the largest entry) for
+/// them to count as the same repeated eigenvalue
+const EIGENVALUE_CLUSTER_TOLERANCE: f64 = 1e-8;
+
+/// Largest residual `|Av - λv|` (relative to the largest entry) allowed for a unit eigenvector
+const MAX_EIGENVECTOR_RESIDUAL: f64 = 1e-8;
+
+/// Eigen decomposition of a real symmetric `Matrix`, so that `A = VΛV^T`.
+pub struct SymmetricEigen<T> {
+    /// Eigenvalues sorted in ascending order
+    pub eigenvalues: Vec<T>,
+    /// Orthonormal eigenvectors, column `i` belongs to `eigenvalues[i]`
+    pub eigenvectors: Matrix<T>,
+}
+
+/// Eigen decomposition of a general (possibly non-symmetric) square `Matrix`.
+pub struct Eigen<T> {
+    /// Eigenvalues sorted by real part and then imaginary part, complex
+    /// eigenvalues come in conjugate pairs
+    pub eigenvalues: Vec<Complex<T>>,
+    /// Unit length eigenvectors, column `i` belongs to `eigenvalues[i]`
+    pub eigenvectors: Matrix<Complex<T>>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Compute the eigenvalues and eigenvectors of a symmetric `Matrix`
+    /// using the cyclic Jacobi eigenvalue algorithm.
+    /// NOTE: Only computable for symmetric (A = A^T) matrices, else returns None
+    pub fn symmetric_eigen(&self) -> Option<SymmetricEigen<T>> {
+        // Validity check that it's a square matrix
+        if self.row_size != self.col_size {
+            return None;
+        }
+
+        // A symmetric `Matrix` is stored the same way in either layout
+        let n = self.row_size;
+        let mut a: Vec<f64> = self.data.iter().map(|&x| x.into()).collect();
+
+        // Validity check that it's a symmetric matrix
+        let is_symmetric = (0..n)
+            .all(|row| (row + 1..n).all(|col| (a[row * n + col] - a[col * n + row]).abs() < 1e-10));
+        if !is_symmetric {
+            return None;
+        }
+
+        let mut v = Matrix::<f64>::identity(n).data;
+
+        // Converged once the off diagonal mass is negligible next to the whole matrix
+        let tolerance = a.iter().map(|x| x * x).sum::<f64>() * 1e-30;
+        let off_diagonal = |a: &[f64]| -> f64 {
+            (0..n)
+                .flat_map(|row| {
+                    (0..n)
+                        .filter(move |&col| col != row)
+                        .map(move |col| (row, col))
+                })
+                .map(|(row, col)| a[row * n + col] * a[row * n + col])
+                .sum()
+        };
+
+        let mut sweeps = 0;
+        while off_diagonal(&a) > tolerance {
+            if sweeps == MAX_JACOBI_SWEEPS {
+                return None;
+            }
+            sweeps += 1;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let a_pq = a[p * n + q];
+                    if a_pq == 0.0 {
+                        continue;
+                    }
+
+                    // Rotation angle that zeros out a[p][q]
+                    let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * a_pq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    // A = A * J
+                    (0..n).for_each(|k| {
+                        let (a_kp, a_kq) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * a_kp - s * a_kq;
+                        a[k * n + q] = s * a_kp + c * a_kq;
+                    });
+                    // A = J^T * A
+                    (0..n).for_each(|k| {
+                        let (a_pk, a_qk) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * a_pk - s * a_qk;
+                        a[q * n + k] = s * a_pk + c * a_qk;
+                    });
+                    // V = V * J
+                    (0..n).for_each(|k| {
+                        let (v_kp, v_kq) = (v[k * n + p], v[k * n + q]);
+                        v[k * n + p] = c * v_kp - s * v_kq;
+                        v[k * n + q] = s * v_kp + c * v_kq;
+                    });
+                }
+            }
+        }
+
+        // Sort the eigen pairs by eigenvalue
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i * n + i].total_cmp(&a[j * n + j]));
+
+        let eigenvalues = order.iter().map(|&i| T::from(a[i * n + i])).collect();
+        let eigenvectors = (0..n * n)
+            .map(|idx| T::from(v[(idx / n) * n + order[idx % n]]))
+            .collect();
+
+        Some(SymmetricEigen {
+            eigenvalues,
+            eigenvectors: Matrix {
+                data: eigenvectors,
+                row_size: n,
+                col_size: n,
+                layout: Layout::RowMajor,
+            },
+        })
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Compute the (possibly complex) eigenvalues of a `Matrix` by reducing it to
+    /// upper hessenberg form, then running the Francis double shift QR algorithm.
+    /// NOTE: Only computable for square (M x M) matrices.
+    /// NOTE: Returns None if the QR iterations fail to converge.
+    pub fn eigenvalues(&self) -> Option<Vec<Complex<T>>> {
+        Some(
+            self.eigenvalues_f64()?
+                .into_iter()
+                .map(|z| Complex::new(T::from(z.re), T::from(z.im)))
+                .collect(),
+        )
+    }
+
+    /// Compute the (possibly complex) eigenvalues and eigenvectors of a `Matrix`.
+    /// The eigenvectors are found with inverse iteration on each eigenvalue, keeping the
+    /// eigenvectors of a repeated eigenvalue orthogonal to each other.
+    /// NOTE: Only computable for square (M x M) matrices.
+    /// NOTE: Returns None if the QR iterations fail to converge, or if the `Matrix`
+    /// is defective (it doesn't have N independent eigenvectors, e.g. a Jordan block).
+    pub fn eigen(&self) -> Option<Eigen<T>> {
+        let n = self.row_size;
+        let eigenvalues = self.eigenvalues_f64()?;
+        let a: Vec<Complex<f64>> = self
+            .row_major_data()
+            .iter()
+            .map(|&x| Complex::new(x.into(), 0.0))
+            .collect();
+        let a_norm = a.iter().map(|z| z.norm()).fold(0.0, f64::max).max(1.0);
+
+        let mut vectors: Vec<Vec<Complex<f64>>> = Vec::with_capacity(n);
+        for (i, &lambda) in eigenvalues.iter().enumerate() {
+            // Eigenvectors already found for this eigenvalue, if it's repeated
+            let found: Vec<&[Complex<f64>]> = (0..i)
+                .filter(|&j| {
+                    (eigenvalues[j] - lambda).norm() <= EIGENVALUE_CLUSTER_TOLERANCE * a_norm
+                })
+                .map(|j| vectors[j].as_slice())
+                .collect();
+            let v = inverse_iteration(&a, n, lambda, &found, i as u64);
+            vectors.push(v);
+        }
+
+        // Validity check that every vector is an eigenvector, which fails for a repeated
+        // eigenvalue without enough independent eigenvectors (a defective `Matrix`)
+        let residual = |v: &[Complex<f64>], lambda: Complex<f64>| {
+            (0..n)
+                .map(|row| {
+                    (0..n)
+                        .fold(Complex::new(0.0, 0.0) - lambda * v[row], |acc, col| {
+                            acc + a[row * n + col] * v[col]
+                        })
+                        .norm_sqr()
+                })
+                .sum::<f64>()
+                .sqrt()
+        };
+        let is_eigenvector = vectors
+            .iter()
+            .zip(&eigenvalues)
+            .all(|(v, &lambda)| residual(v, lambda) <= MAX_EIGENVECTOR_RESIDUAL * a_norm);
+
+        // Validity check that the eigenvectors are a basis
+        let is_basis = (0..n).all(|i| {
+            (i + 1..n)
+                .all(|j| inner_product(&vectors[i], &vectors[j]).norm() < MAX_EIGENVECTOR_OVERLAP)
+        });
+        if !is_eigenvector || !is_basis {
+            return None;
+        }
+
+        let columns: Vec<Vec<Complex<T>>> = vectors
+            .into_iter()
+            .map(|v| {
+                v.into_iter()
+                    .map(|z| Complex::new(T::from(z.re), T::from(z.im)))
+                    .collect()
+            })
+            .collect();
+
+        Some(Eigen {
+            eigenvalues: eigenvalues
+                .into_iter()
+                .map(|z| Complex::new(T::from(z.re), T::from(z.im)))
+                .collect(),
+            eigenvectors: Matrix {
+                data: Matrix::from_columns(columns).data,
+                row_size: n,
+                col_size: n,
+                layout: Layout::RowMajor,
+            },
+        })
+    }
+
+    /// Compute the sorted eigenvalues of a `Matrix` in f64 precision
+    fn eigenvalues_f64(&self) -> Option<Vec<Complex<f64>>> {
+        // Validity check that it's a square matrix
+        if self.row_size != self.col_size {
+            return None;
+        }
+
+        // A^T has the same eigenvalues as A, so a column major `Matrix` can be read as is
+        let n = self.row_size;
+        let mut a: Vec<f64> = self.data.iter().map(|&x| x.into()).collect();
+        reduce_to_hessenberg(&mut a, n);
+
+        let mut eigenvalues = hessenberg_qr(&a, n)?;
+        eigenvalues.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+
+        Some(eigenvalues)
+    }
+}
+
+/// Reduce a row major NxN matrix to upper hessenberg form in place,
+/// using householder similarity transforms (so eigenvalues are preserved).
+fn reduce_to_hessenberg(a: &mut [f64], n: usize) {
+    for k in 0..n.saturating_sub(2) {
+        let mut v: Vec<f64> = (k + 1..n).map(|row| a[row * n + k]).collect();
+        let norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm == 0.0 {
+            continue;
+        }
+
+        // Reflect away from the sign of the leading entry to avoid cancellation
+        v[0] += v[0].signum() * norm;
+        let v_norm_sq: f64 = v.iter().map(|x| x * x).sum();
+
+        // A = H * A
+        (0..n).for_each(|col| {
+            let dot: f64 = v
+                .iter()
+                .enumerate()
+                .map(|(i, vi)| vi * a[(k + 1 + i) * n + col])
+                .sum();
+            let scale = 2.0 * dot / v_norm_sq;
+            v.iter()
+                .enumerate()
+                .for_each(|(i, vi)| a[(k + 1 + i) * n + col] -= scale * vi);
+        });
+
+        // A = A * H
+        (0..n).for_each(|row| {
+            let dot: f64 = v
+                .iter()
+                .enumerate()
+                .map(|(i, vi)| a[row * n + k + 1 + i] * vi)
+                .sum();
+            let scale = 2.0 * dot / v_norm_sq;
+            v.iter()
+                .enumerate()
+                .for_each(|(i, vi)| a[row * n + k + 1 + i] -= scale * vi);
+        });
+
+        // Entries below the subdiagonal are zero by construction
+        (k + 2..n).for_each(|row| a[row * n + k] = 0.0);
+    }
+}
+
+/// Find all eigenvalues of a row major NxN upper hessenberg matrix using
+/// the implicit Francis double shift QR algorithm, with deflation and exceptional shifts.
+///
+/// NOTE: This follows the textbook description of the Francis step (Golub & Van Loan,
+/// "Matrix Computations", algorithm 7.5.1) and the exceptional shifts of LAPACK's `dlahqr`.
+/// Only the active (undeflated) window is updated, since just the eigenvalues are needed.
+fn hessenberg_qr(hessenberg: &[f64], n: usize) -> Option<Vec<Complex<f64>>> {
+    let mut h = hessenberg.to_vec();
+    let norm = h.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let mut eigenvalues = Vec::with_capacity(n);
+
+    // The active window is rows and columns lo..hi, everything from hi on is deflated
+    let mut hi = n;
+    let mut iterations = 0;
+    while hi > 0 {
+        // Split off the trailing block at the last negligible subdiagonal entry
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let scale = match h[(lo - 1) * n + lo - 1].abs() + h[lo * n + lo].abs() {
+                0.0 => norm,
+                scale => scale,
+            };
+            if h[lo * n + lo - 1].abs() <= f64::EPSILON * scale {
+                h[lo * n + lo - 1] = 0.0;
+                break;
+            }
+            lo -= 1;
+        }
+
+        match hi - lo {
+            1 => {
+                eigenvalues.push(Complex::new(h[lo * n + lo], 0.0));
+                hi -= 1;
+                iterations = 0;
+            }
+            2 => {
+                let block = [
+                    h[lo * n + lo],
+                    h[lo * n + lo + 1],
+                    h[(lo + 1) * n + lo],
+                    h[(lo + 1) * n + lo + 1],
+                ];
+                eigenvalues.extend(block_eigenvalues(block));
+                hi -= 2;
+                iterations = 0;
+            }
+            _ => {
+                if iterations == MAX_QR_ITERATIONS {
+                    return None;
+                }
+                iterations += 1;
+
+                // Shifts are the eigenvalues of the trailing 2x2 block, given by its
+                // trace and determinant, with an ad hoc shift every 10 iterations
+                // to break out of cycles
+                let (trace, determinant) = match iterations % 10 == 0 {
+                    true => {
+                        let s = h[(hi - 1) * n + hi - 2].abs() + h[(hi - 2) * n + hi - 3].abs();
+                        let diagonal = 0.75 * s + h[(hi - 1) * n + hi - 1];
+                        (2.0 * diagonal, diagonal * diagonal + 0.4375 * s * s)
+                    }
+                    false => {
+                        let (a, b) = (h[(hi - 2) * n + hi - 2], h[(hi - 2) * n + hi - 1]);
+                        let (c, d) = (h[(hi - 1) * n + hi - 2], h[(hi - 1) * n + hi - 1]);
+                        (a + d, a * d - b * c)
+                    }
+                };
+                francis_step(&mut h, n, lo..hi, trace, determinant);
+            }
+        }
+    }
+
+    Some(eigenvalues)
+}
+
+/// Compute the 2 (real or complex conjugate) eigenvalues of a row major 2x2 block
+fn block_eigenvalues([a, b, c, d]: [f64; 4]) -> [Complex<f64>; 2] {
+    let mean = 0.5 * (a + d);
+    let half_gap = 0.5 * (a - d);
+    let discriminant = half_gap * half_gap + b * c;
+    let root = discriminant.abs().sqrt();
+
+    match discriminant >= 0.0 {
+        true => {
+            // Add roots of the same sign to avoid cancellation, and get the
+            // other one from the product of the eigenvalues (the determinant)
+            let large = mean + root.copysign(mean);
+            let small = match large == 0.0 {
+                true => 0.0,
+                false => (a * d - b * c) / large,
+            };
+            [Complex::new(large, 0.0), Complex::new(small, 0.0)]
+        }
+        false => [Complex::new(mean, root), Complex::new(mean, -root)],
+    }
+}
+
+/// Run one implicit double shift QR step on the `window` of a row major NxN
+/// upper hessenberg matrix, for the shifts with the given sum and product.
+///
+/// NOTE: The first column of `(H - s1 I)(H - s2 I)` only has 3 non zero entries,
+/// a reflector maps it onto e1 and the resulting bulge is chased down the subdiagonal.
+fn francis_step(h: &mut [f64], n: usize, window: Range<usize>, trace: f64, determinant: f64) {
+    let (lo, hi) = (window.start, window.end);
+    let at = |row: usize, col: usize| row * n + col;
+
+    let mut bulge = [
+        h[at(lo, lo)] * h[at(lo, lo)] + h[at(lo, lo + 1)] * h[at(lo + 1, lo)]
+            - trace * h[at(lo, lo)]
+            + determinant,
+        h[at(lo + 1, lo)] * (h[at(lo, lo)] + h[at(lo + 1, lo + 1)] - trace),
+        h[at(lo + 1, lo)] * h[at(lo + 2, lo + 1)],
+    ];
+
+    for k in lo..hi - 1 {
+        // The last step only has a 2 entry bulge left
+        let len = (hi - k).min(3);
+        let mut v = bulge;
+        let beta = householder(&mut v[..len]);
+
+        if beta != 0.0 {
+            // H = P * H, on the rows the reflector touches
+            (k.max(lo + 1) - 1..hi).for_each(|col| {
+                let dot = (0..len).map(|i| v[i] * h[at(k + i, col)]).sum::<f64>() * beta;
+                (0..len).for_each(|i| h[at(k + i, col)] -= dot * v[i]);
+            });
+            // H = H * P, on the columns the reflector touches
+            (lo..hi.min(k + 4)).for_each(|row| {
+                let dot = (0..len).map(|i| h[at(row, k + i)] * v[i]).sum::<f64>() * beta;
+                (0..len).for_each(|i| h[at(row, k + i)] -= dot * v[i]);
+            });
+        }
+
+        // Entries the reflector just zeroed (left of the bulge) are exactly 0
+        if k > lo {
+            (1..len).for_each(|i| h[at(k + i, k - 1)] = 0.0);
+        }
+
+        if k + 1 < hi - 1 {
+            bulge = [
+                h[at(k + 1, k)],
+                h[at(k + 2, k)],
+                match k + 3 < hi {
+                    true => h[at(k + 3, k)],
+                    false => 0.0,
+                },
+            ];
+        }
+    }
+}
+
+/// Turn `x` into the householder vector `v` of a reflector `I - βvv^T` that maps
+/// `x` onto a multiple of e1, returning `β` (0 when `x` is already zero).
+fn householder(x: &mut [f64]) -> f64 {
+    let norm = x.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm == 0.0 {
+        return 0.0;
+    }
+
+    // Reflect away from the sign of the leading entry to avoid cancellation
+    x[0] += norm.copysign(x[0]);
+    2.0 / x.iter().map(|x| x * x).sum::<f64>()
+}
+
+/// Find a unit length eigenvector of a row major NxN complex matrix for a
+/// known eigenvalue, by repeatedly solving `(A - λI)y = v` from a (pseudo)random
+/// start seeded with `seed`. The result is kept orthogonal to the unit vectors
+/// in `found`, the eigenvectors already found for the same eigenvalue.
+fn inverse_iteration(
+    a: &[Complex<f64>],
+    n: usize,
+    lambda: Complex<f64>,
+    found: &[&[Complex<f64>]],
+    seed: u64,
+) -> Vec<Complex<f64>> {
+    let a_norm = a.iter().map(|z| z.norm()).fold(0.0, f64::max).max(1.0);
+    // `A - λI` is singular by design, so zero pivots get nudged by this much
+    let tiny = a_norm * f64::EPSILON;
+
+    // Factor `A - λI` once with partial pivoting (PM = LU, packed)
+    let mut m: Vec<Complex<f64>> = (0..n * n)
+        .map(|idx| match idx / n == idx % n {
+            true => a[idx] - lambda,
+            false => a[idx],
+        })
+        .collect();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    for i in 0..n {
+        let pivot = (i..n).fold(i, |acc, j| {
+            match m[j * n + i].norm() > m[acc * n + i].norm() {
+                true => j,
+                false => acc,
+            }
+        });
+        if pivot != i {
+            (0..n).for_each(|k| m.swap(i * n + k, pivot * n + k));
+            permutation.swap(i, pivot);
+        }
+        if m[i * n + i].norm() < tiny {
+            m[i * n + i] = Complex::new(tiny, 0.0);
+        }
+        let pivot_val = m[i * n + i];
+        (i + 1..n).for_each(|j| {
+            let factor = m[j * n + i] / pivot_val;
+            m[j * n + i] = factor;
+            (i + 1..n).for_each(|k| m[j * n + k] = m[j * n + k] - factor * m[i * n + k]);
+        });
+    }
+
+    // A fixed start vector would converge to the same eigenvector every time
+    let mut rng = Rng::new(seed);
+    let mut v: Vec<Complex<f64>> = (0..n)
+        .map(|_| Complex::new(rng.next_f64() - 0.5, 0.0))
+        .collect();
+    for _ in 0..INVERSE_ITERATIONS {
+        orthogonalize(&mut v, found);
+
+        // Forward substitution (Ly = Pv), L has a unit diagonal
+        let mut y: Vec<Complex<f64>> = permutation.iter().map(|&row| v[row]).collect();
+        (0..n).for_each(|i| {
+            y[i] = (0..i).fold(y[i], |acc, k| acc - m[i * n + k] * y[k]);
+        });
+
+        // Back substitution (Ux = y)
+        (0..n).rev().for_each(|i| {
+            let sum = (i + 1..n).fold(y[i], |acc, k| acc - m[i * n + k] * y[k]);
+            y[i] = sum / m[i * n + i];
+        });
+
+        orthogonalize(&mut y, found);
+        let norm = y.iter().map(|z| z.norm_sqr()).sum::<f64>().sqrt();
+        v = y.into_iter().map(|z| z / Complex::new(norm, 0.0)).collect();
+    }
+
+    v
+}
+
+/// Remove the components of `v` along each of the orthonormal vectors in `basis` (Gram-Schmidt)
+fn orthogonalize(v: &mut [Complex<f64>], basis: &[&[Complex<f64>]]) {
+    basis.iter().for_each(|u| {
+        let projection = inner_product(u, v);
+        v.iter_mut()
+            .zip(u.iter())
+            .for_each(|(x, &y)| *x = *x - projection * y);
+    });
+}
+
+/// Complex inner product `u^H v`
+fn inner_product(u: &[Complex<f64>], v: &[Complex<f64>]) -> Complex<f64> {
+    u.iter()
+        .zip(v)
+        .fold(Complex::new(0.0, 0.0), |acc, (&x, &y)| acc + x.conj() * y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check if 2 float value's are *ABOUT* equal
+    fn approx_equal(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(&a, &b)| (a - b).abs() < epsilon)
+    }
+
+    #[test]
+    fn test_symmetric_eigen_2x2() {
+        let matrix = Matrix {
+            data: vec![2.0, 1.0, 1.0, 2.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let eigen = matrix.symmetric_eigen().unwrap();
+
+        assert!(approx_equal(&eigen.eigenvalues, &[1.0, 3.0], 1e-12));
+    }
+
+    #[test]
+    /// Verify `Av = λv` holds for every eigen pair
+    fn test_symmetric_eigen_pairs() {
+        let matrix = Matrix {
+            data: vec![
+                4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+            ],
+            row_size: 4,
+            col_size: 4,
+            layout: Layout::RowMajor,
+        };
+
+        let eigen = matrix.symmetric_eigen().unwrap();
+
+        (0..4).for_each(|i| {
+            let v = eigen.eigenvectors.try_get_column(i).unwrap();
+            let av = matrix.vector_multiply(&v).unwrap();
+            let lambda_v: Vec<f64> = v.iter().map(|x| x * eigen.eigenvalues[i]).collect();
+            assert!(approx_equal(&av, &lambda_v, 1e-10));
+        });
+    }
+
+    #[test]
+    fn test_symmetric_eigen_sorted() {
+        let matrix = Matrix {
+            data: vec![5.0, 0.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 2.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let eigen = matrix.symmetric_eigen().unwrap();
+
+        assert!(approx_equal(&eigen.eigenvalues, &[-1.0, 2.0, 5.0], 1e-12));
+        assert!(approx_equal(
+            &eigen.eigenvectors.data,
+            &[0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            1e-12
+        ));
+    }
+
+    #[test]
+    /// Verify `V^T * V = I`
+    fn test_symmetric_eigen_orthonormal() {
+        let matrix = Matrix {
+            data: vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let eigen = matrix.symmetric_eigen().unwrap();
+        let product = eigen
+            .eigenvectors
+            .transpose()
+            .multiply(&eigen.eigenvectors)
+            .unwrap();
+
+        assert!(approx_equal(
+            &product.data,
+            &Matrix::<f64>::identity(3).data,
+            1e-12
+        ));
+        // Known eigenvalues of the 1D laplacian, 2 - 2cos(kπ/4)
+        let sqrt_2 = 2.0_f64.sqrt();
+        assert!(approx_equal(
+            &eigen.eigenvalues,
+            &[2.0 - sqrt_2, 2.0, 2.0 + sqrt_2],
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn test_symmetric_eigen_not_symmetric() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        assert!(matrix.symmetric_eigen().is_none());
+    }
+
+    #[test]
+    fn test_symmetric_eigen_non_square() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        assert!(matrix.symmetric_eigen().is_none());
+    }
+
+    /// Check if 2 complex vectors are *ABOUT* equal
+    fn approx_equal_complex(a: &[Complex<f64>], b: &[Complex<f64>], epsilon: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(&a, &b)| (a - b).norm() < epsilon)
+    }
+
+    #[test]
+    fn test_eigenvalues_real() {
+        let matrix = Matrix {
+            data: vec![2.0, 0.0, 0.0, 1.0, 3.0, 0.0, 4.0, 5.0, 6.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.eigenvalues().unwrap();
+        let expected = [
+            Complex::new(2.0, 0.0),
+            Complex::new(3.0, 0.0),
+            Complex::new(6.0, 0.0),
+        ];
+
+        assert!(approx_equal_complex(&result, &expected, 1e-10));
+    }
+
+    #[test]
+    /// Verify a rotation matrix has the complex conjugate eigenvalues ±i
+    fn test_eigenvalues_complex() {
+        let matrix = Matrix {
+            data: vec![0.0, -1.0, 1.0, 0.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.eigenvalues().unwrap();
+        let expected = [Complex::new(0.0, -1.0), Complex::new(0.0, 1.0)];
+
+        assert!(approx_equal_complex(&result, &expected, 1e-12));
+    }
+
+    #[test]
+    /// Verify the eigenvalues of a companion matrix are the roots of its polynomial,
+    /// (x - 1)(x - 2)(x^2 + 1) = x^4 - 3x^3 + 3x^2 - 3x + 2
+    fn test_eigenvalues_companion_matrix() {
+        let matrix = Matrix {
+            data: vec![
+                3.0, -3.0, 3.0, -2.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0,
+            ],
+            row_size: 4,
+            col_size: 4,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.eigenvalues().unwrap();
+        let expected = [
+            Complex::new(0.0, -1.0),
+            Complex::new(0.0, 1.0),
+            Complex::new(1.0, 0.0),
+            Complex::new(2.0, 0.0),
+        ];
+
+        assert!(approx_equal_complex(&result, &expected, 1e-9));
+    }
+
+    #[test]
+    /// Verify `Av = λv` holds for every (possibly complex) eigen pair
+    fn test_eigen_pairs() {
+        let matrix = Matrix {
+            data: vec![
+                1.0, 2.0, 0.0, -1.0, 0.5, 3.0, 1.0, 0.0, -2.0, 1.0, 1.0, 4.0, 0.0, 2.0, -1.0, 0.0,
+            ],
+            row_size: 4,
+            col_size: 4,
+            layout: Layout::RowMajor,
+        };
+        let complex_matrix = Matrix {
+            data: matrix.data.iter().map(|&x| Complex::new(x, 0.0)).collect(),
+            row_size: 4,
+            col_size: 4,
+            layout: Layout::RowMajor,
+        };
+
+        let eigen = matrix.eigen().unwrap();
+
+        assert!(eigen.eigenvalues.iter().any(|z| z.im.abs() > 1e-6));
+        (0..4).for_each(|i| {
+            let v = eigen.eigenvectors.try_get_column(i).unwrap();
+            let av = complex_matrix.vector_multiply(&v).unwrap();
+            let lambda_v: Vec<Complex<f64>> = v.iter().map(|&x| x * eigen.eigenvalues[i]).collect();
+
+            assert!((v.iter().map(|z| z.norm_sqr()).sum::<f64>() - 1.0).abs() < 1e-10);
+            assert!(approx_equal_complex(&av, &lambda_v, 1e-8));
+        });
+    }
+
+    #[test]
+    /// Verify the general solver agrees with the symmetric one on a symmetric matrix
+    fn test_eigenvalues_match_symmetric_eigen() {
+        let matrix = Matrix {
+            data: vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let general: Vec<f64> = matrix
+            .eigenvalues()
+            .unwrap()
+            .into_iter()
+            .map(|z| z.re)
+            .collect();
+        let symmetric = matrix.symmetric_eigen().unwrap().eigenvalues;
+
+        assert!(approx_equal(&general, &symmetric, 1e-10));
+    }
+
+    #[test]
+    /// Verify the eigenvalues of a larger matrix add up to its trace and multiply to its determinant
+    fn test_eigenvalues_trace_and_determinant() {
+        let n = 12;
+        let matrix = Matrix {
+            data: (0..n * n).map(|i| ((i * 17) % 13) as f64 - 6.0).collect(),
+            row_size: n,
+            col_size: n,
+            layout: Layout::RowMajor,
+        };
+
+        let eigenvalues = matrix.eigenvalues().unwrap();
+        let sum = eigenvalues
+            .iter()
+            .fold(Complex::new(0.0, 0.0), |acc, &z| acc + z);
+        let product = eigenvalues
+            .iter()
+            .fold(Complex::new(1.0, 0.0), |acc, &z| acc * z);
+        let trace: f64 = matrix.get_diagonal().iter().sum();
+        let determinant = matrix.determinant().unwrap();
+
+        assert!((sum - Complex::new(trace, 0.0)).norm() < 1e-9);
+        assert!((product - Complex::new(determinant, 0.0)).norm() < 1e-6 * determinant.abs());
+    }
+
+    #[test]
+    /// Verify a cyclic permutation (where plain shifts stall) converges to the cube roots of 1
+    fn test_eigenvalues_cyclic_permutation() {
+        let matrix = Matrix {
+            data: vec![0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.eigenvalues().unwrap();
+        let half_sqrt_3 = 3.0_f64.sqrt() / 2.0;
+        let expected = [
+            Complex::new(-0.5, -half_sqrt_3),
+            Complex::new(-0.5, half_sqrt_3),
+            Complex::new(1.0, 0.0),
+        ];
+
+        assert!(approx_equal_complex(&result, &expected, 1e-10));
+    }
+
+    /// Verify every column of `eigen.eigenvectors` is a unit vector with `Av = λv`,
+    /// and that the columns are independent (their gram matrix is invertible)
+    fn assert_eigen_basis(matrix: &Matrix<f64>, eigen: &Eigen<f64>) {
+        let n = matrix.row_size;
+        let complex_matrix = matrix.map(|&x| Complex::new(x, 0.0));
+        let vectors: Vec<Vec<Complex<f64>>> = (0..n)
+            .map(|i| eigen.eigenvectors.try_get_column(i).unwrap())
+            .collect();
+
+        vectors
+            .iter()
+            .zip(&eigen.eigenvalues)
+            .for_each(|(v, &lambda)| {
+                let av = complex_matrix.vector_multiply(v).unwrap();
+                let lambda_v: Vec<Complex<f64>> = v.iter().map(|&x| x * lambda).collect();
+
+                assert!((v.iter().map(|z| z.norm_sqr()).sum::<f64>() - 1.0).abs() < 1e-10);
+                assert!(approx_equal_complex(&av, &lambda_v, 1e-8));
+            });
+
+        let gram = Matrix {
+            data: (0..n * n)
+                .map(|idx| inner_product(&vectors[idx / n], &vectors[idx % n]))
+                .collect(),
+            row_size: n,
+            col_size: n,
+            layout: Layout::RowMajor,
+        };
+        let real_gram = gram.map(|z| z.re);
+        assert!(real_gram.determinant().unwrap() > 1e-6);
+    }
+
+    #[test]
+    /// Verify the identity gets a full (orthonormal) set of eigenvectors
+    fn test_eigen_identity() {
+        let identity = Matrix::<f64>::identity(3);
+        let eigen = identity.eigen().unwrap();
+
+        assert!(approx_equal_complex(
+            &eigen.eigenvalues,
+            &[Complex::new(1.0, 0.0); 3],
+            1e-12
+        ));
+        assert_eigen_basis(&identity, &eigen);
+    }
+
+    #[test]
+    /// Verify a non symmetric matrix with a double eigenvalue gets 2 independent eigenvectors for it
+    fn test_eigen_double_eigenvalue() {
+        // Eigenvalues 2, 2 and 4
+        let matrix = Matrix {
+            data: vec![3.0, 1.0, 1.0, 1.0, 3.0, 1.0, 0.0, 0.0, 2.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        let eigen = matrix.eigen().unwrap();
+
+        let expected = [2.0, 2.0, 4.0].map(|x| Complex::new(x, 0.0));
+        assert!(approx_equal_complex(&eigen.eigenvalues, &expected, 1e-10));
+        assert_eigen_basis(&matrix, &eigen);
+    }
+
+    #[test]
+    /// Verify defective matrices (repeated eigenvalues, too few eigenvectors) are rejected by `eigen`
+    fn test_eigen_defective() {
+        let jordan_block = Matrix {
+            data: vec![2.0, 1.0, 0.0, 2.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert!(jordan_block.eigenvalues().is_some());
+        assert!(jordan_block.eigen().is_none());
+    }
+
+    #[test]
+    fn test_eigenvalues_non_square() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        assert!(matrix.eigenvalues().is_none());
+        assert!(matrix.eigen().is_none());
+    }
+}
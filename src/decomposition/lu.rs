@@ -0,0 +1,374 @@
+use crate::matrix::Abs;
+use crate::{Layout, Matrix, MatrixError};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// LU decomposition of a square `Matrix` with partial pivoting, so that `PA = LU`.
+///
+/// Factor once with `Matrix::lu` and reuse the result for solves,
+/// determinants and inverses.
+pub struct Lu<T> {
+    /// Unit lower triangular factor
+    pub l: Matrix<T>,
+    /// Upper triangular factor
+    pub u: Matrix<T>,
+    /// Row permutation, row `i` of `PA` is row `permutation[i]` of `A`
+    pub permutation: Vec<usize>,
+    /// Sign of the permutation, 1 for an even number of row swaps and -1 for odd
+    pub sign: i8,
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Compute the LU decomposition (with partial pivoting) of a `Matrix`
+    /// NOTE: Only computable for square (M x M) matrices.
+    /// NOTE: Singular matrices still factor, check `Lu::is_singular` before solving.
+    pub fn lu(&self) -> Option<Lu<T>> {
+        // Validity check that it's a square matrix
+        if self.row_size != self.col_size {
+            return None;
+        }
+
+        let n = self.row_size;
+        let epsilon = T::from(1e-10);
+
+        // Packed factors, L below the diagonal and U on and above it
+        let mut a = self.row_major_data().into_owned();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1;
+
+        for i in 0..n {
+            let pivot = (i..n).fold(i, |acc, j| {
+                match a[j * n + i].abs() > a[acc * n + i].abs() {
+                    true => j,
+                    false => acc,
+                }
+            });
+
+            if pivot != i {
+                (0..n).for_each(|k| a.swap(i * n + k, pivot * n + k));
+                permutation.swap(i, pivot);
+                sign = -sign;
+            }
+
+            // A (numerically) zero pivot means this column is already
+            // eliminated, so there is nothing left to do for it
+            if a[i * n + i].abs() < epsilon {
+                (i + 1..n).for_each(|j| a[j * n + i] = T::default());
+                continue;
+            }
+
+            let pivot_val = a[i * n + i];
+            (i + 1..n).for_each(|j| {
+                let factor = a[j * n + i] / pivot_val;
+                a[j * n + i] = factor;
+                (i + 1..n).for_each(|k| {
+                    a[j * n + k] = a[j * n + k] - factor * a[i * n + k];
+                })
+            })
+        }
+
+        let l = (0..n * n)
+            .map(|idx| {
+                let (row, col) = (idx / n, idx % n);
+                match row.cmp(&col) {
+                    std::cmp::Ordering::Greater => a[idx],
+                    std::cmp::Ordering::Equal => T::from(1.0),
+                    std::cmp::Ordering::Less => T::default(),
+                }
+            })
+            .collect();
+        let u = (0..n * n)
+            .map(|idx| match idx / n <= idx % n {
+                true => a[idx],
+                false => T::default(),
+            })
+            .collect();
+
+        Some(Lu {
+            l: Matrix {
+                data: l,
+                row_size: n,
+                col_size: n,
+                layout: Layout::RowMajor,
+            },
+            u: Matrix {
+                data: u,
+                row_size: n,
+                col_size: n,
+                layout: Layout::RowMajor,
+            },
+            permutation,
+            sign,
+        })
+    }
+
+    /// Compute the LU decomposition (with partial pivoting) of a `Matrix`,
+    /// returning an error for a non square `Matrix`
+    pub fn try_lu(&self) -> Result<Lu<T>, MatrixError> {
+        self.lu().ok_or(MatrixError::NonSquare {
+            shape: self.shape(),
+        })
+    }
+}
+
+impl<T> Lu<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Check if the factored `Matrix` is singular (has a pivot of about 0)
+    pub fn is_singular(&self) -> bool {
+        let epsilon = T::from(1e-10);
+        self.u.get_diagonal().into_iter().any(|d| d.abs() < epsilon)
+    }
+
+    /// Compute the determinant of the factored `Matrix`
+    pub fn determinant(&self) -> T {
+        self.u
+            .get_diagonal()
+            .into_iter()
+            .fold(T::from(self.sign as f64), |acc, d| acc * d)
+    }
+
+    /// Solve `Ax = b` for `x` using the factored `Matrix` as `A`
+    /// NOTE: The vectors length MUST match the matrix size, else returns None
+    /// NOTE: Returns None for a singular `Matrix`.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        let n = self.u.row_size;
+
+        // Validity check that the vector matches the matrix size
+        if b.len() != n || self.is_singular() {
+            return None;
+        }
+
+        // Forward substitution (Ly = Pb), L has a unit diagonal
+        let mut x: Vec<T> = self.permutation.iter().map(|&row| b[row]).collect();
+        (0..n).for_each(|i| {
+            x[i] = (0..i).fold(x[i], |acc, k| acc - self.l.data[i * n + k] * x[k]);
+        });
+
+        // Back substitution (Ux = y)
+        (0..n).rev().for_each(|i| {
+            let sum = (i + 1..n).fold(x[i], |acc, k| acc - self.u.data[i * n + k] * x[k]);
+            x[i] = sum / self.u.data[i * n + i];
+        });
+
+        Some(x)
+    }
+
+    /// Solve `AX = B` for `X` using the factored `Matrix` as `A`, where each
+    /// column of `B` is a separate right hand side.
+    /// NOTE: The row size of `B` MUST match the matrix size, else returns None
+    /// NOTE: Returns None for a singular `Matrix`.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        // Validity check that the right hand sides match the matrix size
+        if b.row_size != self.u.row_size {
+            return None;
+        }
+
+        let columns = (0..b.col_size)
+            .map(|col| self.solve(&b.try_get_column(col)?))
+            .collect::<Option<Vec<Vec<T>>>>()?;
+
+        Some(Matrix {
+            data: Matrix::from_columns(columns).data,
+            row_size: b.row_size,
+            col_size: b.col_size,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Compute the inverse of the factored `Matrix`
+    /// NOTE: Returns None for a singular `Matrix`.
+    pub fn inverse(&self) -> Option<Matrix<T>> {
+        self.solve_matrix(&Matrix::identity(self.u.row_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check if 2 float value's are *ABOUT* equal
+    fn approx_equal(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(&a, &b)| (a - b).abs() < epsilon)
+    }
+
+    #[test]
+    /// Verify `PA = LU` holds for a matrix that needs pivoting
+    fn test_lu_reconstructs_permuted_matrix() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let lu = matrix.lu().unwrap();
+        let permuted: Vec<f64> = lu
+            .permutation
+            .iter()
+            .flat_map(|&row| matrix.try_get_row(row).unwrap())
+            .collect();
+        let product = lu.l.multiply(&lu.u).unwrap();
+
+        assert_eq!(lu.permutation[0], 2);
+        assert!(approx_equal(&product.data, &permuted, 1e-10));
+    }
+
+    #[test]
+    /// Verify the factors are triangular and L has a unit diagonal
+    fn test_lu_factors_are_triangular() {
+        let matrix = Matrix {
+            data: vec![2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let lu = matrix.lu().unwrap();
+
+        assert_eq!(lu.l.get_diagonal(), vec![1.0, 1.0, 1.0]);
+        (0..3).for_each(|row| {
+            (0..3).for_each(|col| {
+                if row < col {
+                    assert_eq!(*lu.l.get(row, col).unwrap(), 0.0);
+                } else if row > col {
+                    assert_eq!(*lu.u.get(row, col).unwrap(), 0.0);
+                }
+            })
+        });
+    }
+
+    #[test]
+    fn test_lu_non_square_matrix() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        assert!(matrix.lu().is_none());
+    }
+
+    #[test]
+    fn test_lu_determinant() {
+        let matrix = Matrix {
+            data: vec![3.0, 2.0, 1.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let lu = matrix.lu().unwrap();
+        assert!((lu.determinant() - -37.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let matrix = Matrix {
+            data: vec![2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let lu = matrix.lu().unwrap();
+        let x = lu.solve(&[8.0, -11.0, -3.0]).unwrap();
+
+        assert!(approx_equal(&x, &[2.0, 3.0, -1.0], 1e-10));
+    }
+
+    #[test]
+    /// Verify one factorization can be reused for several right hand sides
+    fn test_lu_solve_matrix() {
+        let matrix = Matrix {
+            data: vec![4.0, 7.0, 2.0, 6.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let b = Matrix {
+            data: vec![1.0, 11.0, 0.0, 8.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let lu = matrix.lu().unwrap();
+        let x = lu.solve_matrix(&b).unwrap();
+
+        assert!(approx_equal(&x.data, &[0.6, 1.0, -0.2, 1.0], 1e-10));
+    }
+
+    #[test]
+    fn test_lu_solve_wrong_length() {
+        let matrix = Matrix::<f64>::identity(3);
+        let lu = matrix.lu().unwrap();
+        assert!(lu.solve(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    /// Verify a singular matrix still factors but can't be solved
+    fn test_lu_singular() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let lu = matrix.lu().unwrap();
+
+        assert!(lu.is_singular());
+        assert!(lu.determinant().abs() < 1e-10);
+        assert!(lu.solve(&[1.0, 2.0]).is_none());
+        assert!(lu.inverse().is_none());
+    }
+
+    #[test]
+    fn test_lu_inverse() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let expected_inverse = vec![-24.0, 18.0, 5.0, 20.0, -15.0, -4.0, -5.0, 4.0, 1.0];
+        let result = matrix.lu().unwrap().inverse().unwrap();
+
+        assert!(approx_equal(&result.data, &expected_inverse, 1e-6));
+    }
+
+    #[test]
+    fn test_try_lu_non_square() {
+        let matrix = Matrix::<f64>::new(2, 3);
+        assert_eq!(
+            matrix.try_lu().err(),
+            Some(MatrixError::NonSquare { shape: (2, 3) })
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! Matrix decompositions (factorizations) and the solvers built on top of them.
+
+pub mod cholesky;
+pub mod eigen;
+pub mod lu;
+pub mod qr;
+pub mod svd;
+
+pub use cholesky::Cholesky;
+pub use eigen::{Eigen, SymmetricEigen};
+pub use lu::Lu;
+pub use qr::Qr;
+pub use svd::Svd;
@@ -0,0 +1,362 @@
+use crate::matrix::Abs;
+use crate::{Layout, Matrix, MatrixError};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// QR decomposition of an MxN `Matrix`, so that `A = QR`.
+///
+/// Computed with householder reflections, which keeps `Q` orthogonal
+/// to working precision.
+pub struct Qr<T> {
+    /// Orthogonal MxM factor
+    pub q: Matrix<T>,
+    /// Upper triangular MxN factor
+    pub r: Matrix<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Compute the QR decomposition of a `Matrix` using householder reflections
+    pub fn qr(&self) -> Qr<T> {
+        let (m, n) = (self.row_size, self.col_size);
+        let epsilon = T::from(1e-10);
+        let two = T::from(2.0);
+
+        let mut r = self.row_major_data().into_owned();
+        let mut q = Matrix::<T>::identity(m).data;
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            // Householder vector that reflects the column below the
+            // diagonal onto the first axis
+            let mut v: Vec<T> = (k..m).map(|row| r[row * n + k]).collect();
+            let norm: f64 = v
+                .iter()
+                .fold(T::default(), |acc, &x| acc + x * x)
+                .into()
+                .sqrt();
+            if T::from(norm) < epsilon {
+                continue;
+            }
+
+            // Reflect away from the sign of the leading entry to avoid cancellation
+            let alpha = match v[0] > T::default() {
+                true => T::from(-norm),
+                false => T::from(norm),
+            };
+            v[0] = v[0] - alpha;
+
+            let v_norm_sq = v.iter().fold(T::default(), |acc, &x| acc + x * x);
+            if v_norm_sq < epsilon * epsilon {
+                continue;
+            }
+
+            // R = H * R
+            (k..n).for_each(|col| {
+                let dot = v.iter().enumerate().fold(T::default(), |acc, (i, &vi)| {
+                    acc + vi * r[(k + i) * n + col]
+                });
+                let scale = two * dot / v_norm_sq;
+                v.iter().enumerate().for_each(|(i, &vi)| {
+                    r[(k + i) * n + col] = r[(k + i) * n + col] - scale * vi;
+                })
+            });
+
+            // Q = Q * H
+            (0..m).for_each(|row| {
+                let dot = v
+                    .iter()
+                    .enumerate()
+                    .fold(T::default(), |acc, (i, &vi)| acc + q[row * m + k + i] * vi);
+                let scale = two * dot / v_norm_sq;
+                v.iter().enumerate().for_each(|(i, &vi)| {
+                    q[row * m + k + i] = q[row * m + k + i] - scale * vi;
+                })
+            });
+
+            // Entries below the diagonal are zero by construction, so
+            // clear out the rounding noise left in them
+            (k + 1..m).for_each(|row| r[row * n + k] = T::default());
+        }
+
+        Qr {
+            q: Matrix {
+                data: q,
+                row_size: m,
+                col_size: m,
+                layout: Layout::RowMajor,
+            },
+            r: Matrix {
+                data: r,
+                row_size: m,
+                col_size: n,
+                layout: Layout::RowMajor,
+            },
+        }
+    }
+
+    /// Compute the least squares solution `x` minimizing `||Ax - b||`,
+    /// where `A` is the `Matrix`.
+    /// NOTE: The `Matrix` MUST have at least as many rows as columns (M >= N).
+    /// NOTE: The vectors length MUST match the matrix row size, else returns None
+    /// NOTE: Returns None for a `Matrix` without full column rank (r < N).
+    pub fn least_squares(&self, b: &[T]) -> Option<Vec<T>> {
+        self.qr().least_squares(b)
+    }
+
+    /// Compute the least squares solution `x` minimizing `||Ax - b||`, where `A` is
+    /// the `Matrix`, returning an error for a wide or rank deficient `Matrix`
+    /// or a wrongly sized vector
+    pub fn try_least_squares(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        if b.len() != self.row_size {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: (b.len(), 1),
+            });
+        }
+        if self.row_size < self.col_size {
+            return Err(MatrixError::InvalidParameter(
+                "least squares needs at least as many rows as columns".to_string(),
+            ));
+        }
+
+        self.least_squares(b).ok_or(MatrixError::Singular)
+    }
+}
+
+impl<T> Qr<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Compute the least squares solution `x` minimizing `||Ax - b||`,
+    /// using the factored `Matrix` as `A`.
+    /// NOTE: The factored `Matrix` MUST have at least as many rows as columns (M >= N).
+    /// NOTE: The vectors length MUST match the matrix row size, else returns None
+    /// NOTE: Returns None for a `Matrix` without full column rank (r < N).
+    pub fn least_squares(&self, b: &[T]) -> Option<Vec<T>> {
+        let (m, n) = (self.r.row_size, self.r.col_size);
+        let epsilon = T::from(1e-10);
+
+        // Validity check for the system dimensions
+        if b.len() != m || m < n {
+            return None;
+        }
+
+        // Validity check that the matrix has full column rank
+        if self.r.get_diagonal().into_iter().any(|d| d.abs() < epsilon) {
+            return None;
+        }
+
+        // Only the first N entries of Q^T * b are reachable by `Ax`
+        let mut x: Vec<T> = (0..n)
+            .map(|col| {
+                (0..m).fold(T::default(), |acc, row| {
+                    acc + self.q.data[row * m + col] * b[row]
+                })
+            })
+            .collect();
+
+        // Back substitution (Rx = Q^T * b)
+        (0..n).rev().for_each(|i| {
+            let sum = (i + 1..n).fold(x[i], |acc, k| acc - self.r.data[i * n + k] * x[k]);
+            x[i] = sum / self.r.data[i * n + i];
+        });
+
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check if 2 float value's are *ABOUT* equal
+    fn approx_equal(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(&a, &b)| (a - b).abs() < epsilon)
+    }
+
+    #[test]
+    /// Verify `A = QR` for a tall matrix
+    fn test_qr_reconstructs_matrix() {
+        let matrix = Matrix {
+            data: vec![
+                12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0, 1.0, 2.0, 3.0,
+            ],
+            row_size: 4,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let qr = matrix.qr();
+        let product = qr.q.multiply(&qr.r).unwrap();
+
+        assert_eq!((qr.q.row_size, qr.q.col_size), (4, 4));
+        assert_eq!((qr.r.row_size, qr.r.col_size), (4, 3));
+        assert!(approx_equal(&product.data, &matrix.data, 1e-9));
+    }
+
+    #[test]
+    /// Verify `Q^T * Q = I`
+    fn test_qr_q_is_orthogonal() {
+        let matrix = Matrix {
+            data: vec![12.0, -51.0, 4.0, 6.0, 167.0, -68.0, -4.0, 24.0, -41.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let qr = matrix.qr();
+        let product = qr.q.transpose().multiply(&qr.q).unwrap();
+
+        assert!(approx_equal(
+            &product.data,
+            &Matrix::<f64>::identity(3).data,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn test_qr_r_is_upper_triangular() {
+        let matrix = Matrix {
+            data: vec![2.0, 3.0, 1.0, 5.0, 4.0, 6.0, 7.0, 8.0, 9.0, 1.0, 0.0, 2.0],
+            row_size: 4,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let qr = matrix.qr();
+
+        (0..4).for_each(|row| {
+            (0..row.min(3)).for_each(|col| assert_eq!(*qr.r.get(row, col).unwrap(), 0.0))
+        });
+    }
+
+    #[test]
+    /// Verify a wide matrix still factors
+    fn test_qr_wide_matrix() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let qr = matrix.qr();
+        let product = qr.q.multiply(&qr.r).unwrap();
+
+        assert!(approx_equal(&product.data, &matrix.data, 1e-12));
+    }
+
+    #[test]
+    /// Verify the least squares solution fits points on a line exactly
+    fn test_least_squares_exact_line() {
+        // Design matrix for y = c0 + c1 * x
+        let design = Matrix {
+            data: vec![1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0, 1.0, 4.0],
+            row_size: 5,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let y = vec![1.0, 3.0, 5.0, 7.0, 9.0];
+
+        let result = design.least_squares(&y).unwrap();
+
+        assert!(approx_equal(&result, &[1.0, 2.0], 1e-10));
+    }
+
+    #[test]
+    /// Verify the least squares solution for noisy points
+    /// matches the normal equations solution.
+    fn test_least_squares_regression() {
+        let design = Matrix {
+            data: vec![1.0, 1.0, 1.0, 2.0, 1.0, 3.0, 1.0, 4.0],
+            row_size: 4,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let y = vec![6.0, 5.0, 7.0, 10.0];
+
+        let result = design.least_squares(&y).unwrap();
+
+        assert!(approx_equal(&result, &[3.5, 1.4], 1e-10));
+    }
+
+    #[test]
+    fn test_least_squares_rank_deficient() {
+        let design = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0],
+            row_size: 3,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert!(design.least_squares(&[1.0, 2.0, 3.0]).is_none());
+    }
+
+    #[test]
+    fn test_least_squares_invalid_dimensions() {
+        let wide = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        let tall = Matrix {
+            data: vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+            row_size: 3,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert!(wide.least_squares(&[1.0, 2.0]).is_none());
+        assert!(tall.least_squares(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_try_least_squares_errors() {
+        let wide = Matrix::<f64>::new(2, 3);
+        let rank_deficient = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0],
+            row_size: 3,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!(
+            rank_deficient.try_least_squares(&[1.0, 2.0]),
+            Err(MatrixError::ShapeMismatch {
+                left: (3, 2),
+                right: (2, 1)
+            })
+        );
+        assert!(matches!(
+            wide.try_least_squares(&[1.0, 2.0]),
+            Err(MatrixError::InvalidParameter(_))
+        ));
+        assert_eq!(
+            rank_deficient.try_least_squares(&[1.0, 2.0, 3.0]),
+            Err(MatrixError::Singular)
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use crate::matrix::Abs;
+use crate::{Layout, Matrix};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Maximum number of full one sided Jacobi sweeps
+const MAX_JACOBI_SWEEPS: usize = 60;
+
+/// Singular value decomposition of an MxN `Matrix`, so that `A = UΣV^T`.
+///
+/// With K = min(M, N), the thin variant (`Matrix::svd`) has a MxK `U` and a KxN `V^T`,
+/// while the full variant (`Matrix::full_svd`) has a MxM `U` and a NxN `V^T`.
+pub struct Svd<T> {
+    /// Left singular vectors (orthonormal columns)
+    pub u: Matrix<T>,
+    /// The K singular values (diagonal of `Σ`) sorted in descending order
+    pub singular_values: Vec<T>,
+    /// Right singular vectors (orthonormal rows)
+    pub v_t: Matrix<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
+        + Into<f64>
+        + Sub<Output = T>
+        + Add<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs,
+{
+    /// Compute the thin singular value decomposition of a `Matrix`
+    /// using the one sided Jacobi algorithm.
+    pub fn svd(&self) -> Svd<T> {
+        self.compute_svd(false)
+    }
+
+    /// Compute the full singular value decomposition of a `Matrix`, where
+    /// `U` and `V^T` are extended into square orthogonal matrices.
+    pub fn full_svd(&self) -> Svd<T> {
+        self.compute_svd(true)
+    }
+
+    /// Compute the rank of a `Matrix`, the number of singular values
+    /// that aren't (numerically) zero.
+    pub fn rank(&self) -> usize {
+        let singular_values: Vec<f64> = self.singular_values_f64();
+        let tolerance = self.rank_tolerance(&singular_values);
+
+        singular_values
+            .into_iter()
+            .filter(|&s| s > tolerance)
+            .count()
+    }
+
+    /// Compute the Moore-Penrose pseudo inverse of a `Matrix`
+    /// NOTE: Unlike `Matrix::inverse` this works for any MxN `Matrix`,
+    /// including rank deficient ones.
+    pub fn pseudo_inverse(&self) -> Matrix<T> {
+        let svd = self.svd();
+        let (m, n, k) = (self.row_size, self.col_size, svd.singular_values.len());
+
+        let singular_values: Vec<f64> = svd.singular_values.iter().map(|&s| s.into()).collect();
+        let tolerance = self.rank_tolerance(&singular_values);
+
+        // A^+ = V * Σ^+ * U^T, skipping the zero singular values
+        let data = (0..n * m)
+            .map(|idx| {
+                let (row, col) = (idx / m, idx % m);
+                let value: f64 = (0..k)
+                    .filter(|&l| singular_values[l] > tolerance)
+                    .map(|l| {
+                        let v: f64 = svd.v_t.data[l * n + row].into();
+                        let u: f64 = svd.u.data[col * k + l].into();
+                        v * u / singular_values[l]
+                    })
+                    .sum();
+                T::from(value)
+            })
+            .collect();
+
+        Matrix {
+            data,
+            row_size: n,
+            col_size: m,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Compute the (2-norm) condition number of a `Matrix`, the ratio of the
+    /// largest singular value to the smallest one.
+    /// NOTE: This is infinite for a rank deficient `Matrix`, using the same
+    /// tolerance (max(M, N) * σ_max * ε) as `Matrix::rank` to spot zero singular values.
+    pub fn condition_number(&self) -> f64 {
+        let singular_values = self.singular_values_f64();
+        let tolerance = self.rank_tolerance(&singular_values);
+        match (singular_values.first(), singular_values.last()) {
+            (Some(&max), Some(&min)) if min > tolerance => max / min,
+            (Some(_), Some(_)) => f64::INFINITY,
+            _ => 0.0,
+        }
+    }
+
+    /// Compute the spectral norm (induced 2-norm) of a `Matrix`,
+    /// which is its largest singular value.
+    pub fn spectral_norm(&self) -> f64 {
+        self.singular_values_f64().first().copied().unwrap_or(0.0)
+    }
+
+    /// Compute the singular values of a `Matrix` in f64 precision (descending)
+    fn singular_values_f64(&self) -> Vec<f64> {
+        self.svd()
+            .singular_values
+            .into_iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// Threshold below which a singular value counts as zero
+    fn rank_tolerance(&self, singular_values: &[f64]) -> f64 {
+        let max = singular_values.first().copied().unwrap_or(0.0);
+        max * self.row_size.max(self.col_size) as f64 * f64::EPSILON
+    }
+
+    /// Compute the (thin or full) singular value decomposition of a `Matrix`
+    fn compute_svd(&self, full: bool) -> Svd<T> {
+        let (m, n) = (self.row_size, self.col_size);
+        let value = |row: usize, col: usize| -> f64 { self.data[self.offset(row, col)].into() };
+
+        // One sided Jacobi orthogonalizes the columns of a tall matrix, so a wide
+        // matrix gets decomposed through its transpose (A^T = V Σ U^T)
+        let (u_columns, singular_values, v_columns) = if m >= n {
+            let columns = (0..n).map(|col| (0..m).map(|row| value(row, col)).collect());
+            one_sided_jacobi(columns.collect(), m, if full { m } else { n })
+        } else {
+            let columns = (0..m).map(|row| (0..n).map(|col| value(row, col)).collect());
+            let (left, singular_values, right) =
+                one_sided_jacobi(columns.collect(), n, if full { n } else { m });
+            (right, singular_values, left)
+        };
+
+        let (u_size, v_size) = (u_columns.len(), v_columns.len());
+        Svd {
+            u: Matrix {
+                data: (0..m * u_size)
+                    .map(|idx| T::from(u_columns[idx % u_size][idx / u_size]))
+                    .collect(),
+                row_size: m,
+                col_size: u_size,
+                layout: Layout::RowMajor,
+            },
+            singular_values: singular_values.into_iter().map(T::from).collect(),
+            v_t: Matrix {
+                data: (0..v_size * n)
+                    .map(|idx| T::from(v_columns[idx / n][idx % n]))
+                    .collect(),
+                row_size: v_size,
+                col_size: n,
+                layout: Layout::RowMajor,
+            },
+        }
+    }
+}
+
+/// Run the one sided Jacobi SVD on the K columns (of length M >= K) of a matrix.
+///
+/// Returns the `left_size` left singular vectors, the K descending singular
+/// values and the K right singular vectors, all vectors as columns.
+fn one_sided_jacobi(
+    mut w: Vec<Vec<f64>>,
+    m: usize,
+    left_size: usize,
+) -> (Vec<Vec<f64>>, Vec<f64>, Vec<Vec<f64>>) {
+    let k = w.len();
+    let mut v: Vec<Vec<f64>> = (0..k)
+        .map(|i| (0..k).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+    let dot = |a: &[f64], b: &[f64]| -> f64 { a.iter().zip(b).map(|(x, y)| x * y).sum() };
+
+    // Rotate pairs of columns until every pair is orthogonal
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..k {
+            for q in p + 1..k {
+                let alpha = dot(&w[p], &w[p]);
+                let beta = dot(&w[q], &w[q]);
+                let gamma = dot(&w[p], &w[q]);
+                if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+
+                for columns in [&mut w, &mut v] {
+                    (0..columns[p].len()).for_each(|i| {
+                        let (x, y) = (columns[p][i], columns[q][i]);
+                        columns[p][i] = c * x - s * y;
+                        columns[q][i] = s * x + c * y;
+                    });
+                }
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    // The singular values are the lengths of the orthogonalized columns
+    let mut order: Vec<usize> = (0..k).collect();
+    let norms: Vec<f64> = w.iter().map(|col| dot(col, col).sqrt()).collect();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let singular_values: Vec<f64> = order.iter().map(|&i| norms[i]).collect();
+    let tolerance = singular_values.first().copied().unwrap_or(0.0) * m as f64 * f64::EPSILON;
+
+    // Normalize the columns into left singular vectors, the (numerically) zero
+    // ones carry no direction so they get replaced by an orthonormal completion
+    let mut left: Vec<Vec<f64>> = order
+        .iter()
+        .filter(|&&i| norms[i] > tolerance && norms[i] > 0.0)
+        .map(|&i| w[i].iter().map(|x| x / norms[i]).collect())
+        .collect();
+    let missing = left_size - left.len();
+    left.extend(orthonormal_completion(&left, m, missing));
+
+    let right = order.into_iter().map(|i| v[i].clone()).collect();
+
+    (left, singular_values, right)
+}
+
+/// Find `count` unit vectors of length `dim` orthogonal to each other and to
+/// the orthonormal `basis`, using gram-schmidt on the standard basis vectors.
+fn orthonormal_completion(basis: &[Vec<f64>], dim: usize, count: usize) -> Vec<Vec<f64>> {
+    let mut found: Vec<Vec<f64>> = Vec::with_capacity(count);
+
+    for axis in 0..dim {
+        if found.len() == count {
+            break;
+        }
+
+        let mut candidate: Vec<f64> = (0..dim)
+            .map(|i| if i == axis { 1.0 } else { 0.0 })
+            .collect();
+        // Orthogonalize twice to keep rounding errors from creeping back in
+        for _ in 0..2 {
+            basis.iter().chain(found.iter()).for_each(|b| {
+                let projection: f64 = b.iter().zip(&candidate).map(|(x, y)| x * y).sum();
+                candidate
+                    .iter_mut()
+                    .zip(b)
+                    .for_each(|(c, x)| *c -= projection * x);
+            });
+        }
+
+        let norm = candidate.iter().map(|x| x * x).sum::<f64>().sqrt();
+        if norm > 1e-8 {
+            found.push(candidate.into_iter().map(|x| x / norm).collect());
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check if 2 float value's are *ABOUT* equal
+    fn approx_equal(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+        a.len() == b.len()
+            && a.iter()
+                .zip(b.iter())
+                .all(|(&a, &b)| (a - b).abs() < epsilon)
+    }
+
+    /// Rebuild `UΣV^T` from a (thin or full) decomposition
+    fn reconstruct(svd: &Svd<f64>) -> Matrix<f64> {
+        let mut sigma = Matrix::<f64>::new(svd.u.col_size, svd.v_t.row_size);
+        svd.singular_values.iter().enumerate().for_each(|(i, &s)| {
+            sigma.set(i, i, s);
+        });
+
+        svd.u.multiply(&sigma).unwrap().multiply(&svd.v_t).unwrap()
+    }
+
+    /// Check a `Matrix` has orthonormal columns
+    fn has_orthonormal_columns(matrix: &Matrix<f64>) -> bool {
+        let product = matrix.transpose().multiply(matrix).unwrap();
+        approx_equal(
+            &product.data,
+            &Matrix::<f64>::identity(matrix.col_size).data,
+            1e-10,
+        )
+    }
+
+    fn tall_matrix() -> Matrix<f64> {
+        Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0, -1.0, 0.5, 2.0],
+            row_size: 4,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    #[test]
+    fn test_svd_known_singular_values() {
+        let matrix = Matrix {
+            data: vec![3.0, 0.0, 4.0, 5.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let svd = matrix.svd();
+        let sqrt_5 = 5.0_f64.sqrt();
+
+        assert!(approx_equal(
+            &svd.singular_values,
+            &[3.0 * sqrt_5, sqrt_5],
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn test_thin_svd_tall_matrix() {
+        let matrix = tall_matrix();
+        let svd = matrix.svd();
+
+        assert_eq!((svd.u.row_size, svd.u.col_size), (4, 3));
+        assert_eq!((svd.v_t.row_size, svd.v_t.col_size), (3, 3));
+        assert!(has_orthonormal_columns(&svd.u));
+        assert!(has_orthonormal_columns(&svd.v_t.transpose()));
+        assert!(approx_equal(&reconstruct(&svd).data, &matrix.data, 1e-10));
+    }
+
+    #[test]
+    fn test_thin_svd_wide_matrix() {
+        let matrix = tall_matrix().transpose();
+        let svd = matrix.svd();
+
+        assert_eq!((svd.u.row_size, svd.u.col_size), (3, 3));
+        assert_eq!((svd.v_t.row_size, svd.v_t.col_size), (3, 4));
+        assert!(has_orthonormal_columns(&svd.u));
+        assert!(has_orthonormal_columns(&svd.v_t.transpose()));
+        assert!(approx_equal(&reconstruct(&svd).data, &matrix.data, 1e-10));
+    }
+
+    #[test]
+    fn test_full_svd() {
+        let tall = tall_matrix();
+        let wide = tall.transpose();
+
+        let tall_svd = tall.full_svd();
+        let wide_svd = wide.full_svd();
+
+        assert_eq!((tall_svd.u.row_size, tall_svd.u.col_size), (4, 4));
+        assert_eq!((wide_svd.v_t.row_size, wide_svd.v_t.col_size), (4, 4));
+        assert!(has_orthonormal_columns(&tall_svd.u));
+        assert!(has_orthonormal_columns(&wide_svd.v_t.transpose()));
+        assert!(approx_equal(
+            &reconstruct(&tall_svd).data,
+            &tall.data,
+            1e-10
+        ));
+        assert!(approx_equal(
+            &reconstruct(&wide_svd).data,
+            &wide.data,
+            1e-10
+        ));
+    }
+
+    #[test]
+    /// Verify a rank deficient matrix still gets orthonormal singular vectors
+    fn test_svd_rank_deficient() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0],
+            row_size: 3,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let svd = matrix.svd();
+
+        assert!(svd.singular_values[1].abs() < 1e-12);
+        assert!(has_orthonormal_columns(&svd.u));
+        assert!(approx_equal(&reconstruct(&svd).data, &matrix.data, 1e-10));
+    }
+
+    #[test]
+    fn test_rank() {
+        let full_rank = tall_matrix();
+        let rank_one = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0],
+            row_size: 3,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let zero = Matrix::<f64>::new(3, 3);
+
+        assert_eq!(full_rank.rank(), 3);
+        assert_eq!(rank_one.rank(), 1);
+        assert_eq!(zero.rank(), 0);
+    }
+
+    #[test]
+    fn test_pseudo_inverse_matches_inverse() {
+        let matrix = Matrix {
+            data: vec![4.0, 7.0, 2.0, 6.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.pseudo_inverse();
+
+        assert!(approx_equal(&result.data, &[0.6, -0.7, -0.2, 0.4], 1e-10));
+    }
+
+    #[test]
+    /// Verify `AA^+A = A` and `A^+AA^+ = A^+` for a rank deficient matrix
+    fn test_pseudo_inverse_rank_deficient() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0, 3.0, 6.0],
+            row_size: 3,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let pinv = matrix.pseudo_inverse();
+        let a_pinv_a = matrix.multiply(&pinv).unwrap().multiply(&matrix).unwrap();
+        let pinv_a_pinv = pinv.multiply(&matrix).unwrap().multiply(&pinv).unwrap();
+
+        assert_eq!((pinv.row_size, pinv.col_size), (2, 3));
+        assert!(matrix.inverse().is_none());
+        assert!(approx_equal(&a_pinv_a.data, &matrix.data, 1e-10));
+        assert!(approx_equal(&pinv_a_pinv.data, &pinv.data, 1e-10));
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let matrix = Matrix {
+            data: vec![1.0, 0.0, 0.0, 10.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let singular = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert!((matrix.condition_number() - 10.0).abs() < 1e-12);
+        assert_eq!(singular.condition_number(), f64::INFINITY);
+        assert_eq!(singular.rank(), 1);
+    }
+
+    #[test]
+    fn test_spectral_norm() {
+        let matrix = Matrix {
+            data: vec![3.0, 0.0, 4.0, 5.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let expected = 3.0 * 5.0_f64.sqrt();
+        assert!((matrix.spectral_norm() - expected).abs() < 1e-12);
+        assert!(matrix.spectral_norm() <= matrix.frobenius_norm());
+    }
+}
@@ -0,0 +1,426 @@
+use crate::parallel::{self, MIN_PARALLEL_LEN};
+use crate::{Matrix, MatrixError};
+use std::ops::{Add, Div, Mul, Sub};
+
+impl<T> Matrix<T> {
+    /// Create a new `Matrix` of the same shape by applying `f` to every element
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Matrix {
+            data: self.data.iter().map(f).collect(),
+            row_size: self.row_size,
+            col_size: self.col_size,
+            layout: self.layout,
+        }
+    }
+
+    /// Create a new `Matrix` of the same shape by applying `f` to every element,
+    /// splitting large matrices over multiple threads with the `parallel` feature.
+    pub fn par_map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        T: Sync,
+        U: Send,
+        F: Fn(&T) -> U + Sync,
+    {
+        let chunks = parallel::map_chunks(&self.data, MIN_PARALLEL_LEN, 1, |_, chunk| {
+            chunk.iter().map(&f).collect::<Vec<U>>()
+        });
+
+        Matrix {
+            data: parallel::concat(chunks),
+            row_size: self.row_size,
+            col_size: self.col_size,
+            layout: self.layout,
+        }
+    }
+
+    /// Apply `f` to every element of the `Matrix` in place
+    pub fn map_inplace<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Create a new `Matrix` by applying `f` to every pair of matching
+    /// elements from the `Matrix` and `rhs`.
+    /// NOTE: Both matrices MUST have the same dimensionality, else returns an error
+    pub fn zip_map<U, V, F>(&self, rhs: &Matrix<U>, f: F) -> Result<Matrix<V>, MatrixError>
+    where
+        F: Fn(&T, &U) -> V,
+    {
+        // Validity check that both dimensions match
+        if self.shape() != rhs.shape() {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: rhs.shape(),
+            });
+        }
+
+        let data = match self.layout == rhs.layout {
+            true => self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+            // Walk `rhs` in the same element order as the `Matrix`
+            false => self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(idx, a)| {
+                    let (row, col) = self.position(idx);
+                    f(a, &rhs.data[rhs.offset(row, col)])
+                })
+                .collect(),
+        };
+
+        Ok(Matrix {
+            data,
+            row_size: self.row_size,
+            col_size: self.col_size,
+            layout: self.layout,
+        })
+    }
+
+    /// Apply `f` to every element of the `Matrix` in place, along with the
+    /// matching element of `rhs`.
+    /// NOTE: Both matrices MUST have the same dimensionality.
+    pub(crate) fn zip_inplace<U, F>(&mut self, rhs: &Matrix<U>, mut f: F)
+    where
+        F: FnMut(&mut T, &U),
+    {
+        match self.layout == rhs.layout {
+            true => self
+                .data
+                .iter_mut()
+                .zip(&rhs.data)
+                .for_each(|(a, b)| f(a, b)),
+            false => (0..self.data.len()).for_each(|idx| {
+                let (row, col) = self.position(idx);
+                f(&mut self.data[idx], &rhs.data[rhs.offset(row, col)])
+            }),
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Combine every element of the `Matrix` with the matching element of `rhs`.
+    ///
+    /// NOTE: `rhs` is broadcast when it's a 1xN row vector (applied to every row),
+    /// a Mx1 column vector (applied to every column) or a 1x1 scalar matrix,
+    /// any other shape that doesn't match the `Matrix` is an error.
+    fn broadcast_with<F>(&self, rhs: &Matrix<T>, f: F) -> Result<Matrix<T>, MatrixError>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let (rows, cols) = self.shape();
+        let rhs_position: fn(usize, usize) -> (usize, usize) = match rhs.shape() {
+            shape if shape == (rows, cols) => |row, col| (row, col),
+            (1, rhs_cols) if rhs_cols == cols => |_, col| (0, col),
+            (rhs_rows, 1) if rhs_rows == rows => |row, _| (row, 0),
+            (1, 1) => |_, _| (0, 0),
+            _ => {
+                return Err(MatrixError::ShapeMismatch {
+                    left: self.shape(),
+                    right: rhs.shape(),
+                })
+            }
+        };
+
+        // Walk the `Matrix` in memory order, whatever its layout
+        let data = (0..rows * cols)
+            .map(|idx| {
+                let (row, col) = self.position(idx);
+                let (rhs_row, rhs_col) = rhs_position(row, col);
+                f(
+                    self.data[idx].clone(),
+                    rhs.data[rhs.offset(rhs_row, rhs_col)].clone(),
+                )
+            })
+            .collect();
+
+        Ok(Matrix {
+            data,
+            row_size: rows,
+            col_size: cols,
+            layout: self.layout,
+        })
+    }
+
+    /// Add `rhs` to the `Matrix` element wise, broadcasting a 1xN row vector
+    /// (like a bias) over every row or a Mx1 column vector over every column.
+    pub fn broadcast_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Add<Output = T>,
+    {
+        self.broadcast_with(rhs, |a, b| a + b)
+    }
+
+    /// Subtract `rhs` from the `Matrix` element wise, broadcasting a 1xN row
+    /// vector over every row or a Mx1 column vector over every column.
+    pub fn broadcast_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Sub<Output = T>,
+    {
+        self.broadcast_with(rhs, |a, b| a - b)
+    }
+
+    /// Multiply the `Matrix` by `rhs` element wise (hadamard product),
+    /// broadcasting a row or column vector `rhs`.
+    pub fn elementwise_multiply(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.broadcast_with(rhs, |a, b| a * b)
+    }
+
+    /// Divide the `Matrix` by `rhs` element wise,
+    /// broadcasting a row or column vector `rhs`.
+    pub fn elementwise_divide(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Div<Output = T>,
+    {
+        self.broadcast_with(rhs, |a, b| a / b)
+    }
+
+    /// Take the smaller of each element of the `Matrix` and `rhs`,
+    /// broadcasting a row or column vector `rhs`.
+    pub fn elementwise_min(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: PartialOrd,
+    {
+        self.broadcast_with(rhs, |a, b| if b < a { b } else { a })
+    }
+
+    /// Take the larger of each element of the `Matrix` and `rhs`,
+    /// broadcasting a row or column vector `rhs`.
+    pub fn elementwise_max(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: PartialOrd,
+    {
+        self.broadcast_with(rhs, |a, b| if b > a { b } else { a })
+    }
+
+    /// Raise each element of the `Matrix` to the power of the matching element
+    /// of `rhs`, broadcasting a row or column vector `rhs`.
+    pub fn elementwise_pow(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: Into<f64> + From<f64>,
+    {
+        self.broadcast_with(rhs, |a, b| T::from(a.into().powf(b.into())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Layout;
+
+    fn matrix_2x3() -> Matrix<f64> {
+        Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let result = matrix_2x3().map(|&x| x as i32 * 2);
+
+        assert_eq!(result.shape(), (2, 3));
+        assert_eq!(result.data, vec![2, 4, 6, 8, 10, 12]);
+    }
+
+    #[test]
+    /// Verify `par_map` matches `map` on a matrix big enough to be split
+    fn test_par_map() {
+        let matrix = Matrix {
+            data: (0..300 * 300).collect::<Vec<i64>>(),
+            row_size: 300,
+            col_size: 300,
+            layout: Layout::ColumnMajor,
+        };
+
+        let result = matrix.par_map(|&x| x * 3 - 1);
+
+        assert_eq!(result.data, matrix.map(|&x| x * 3 - 1).data);
+        assert_eq!(result.layout, Layout::ColumnMajor);
+    }
+
+    #[test]
+    fn test_map_inplace() {
+        let mut matrix = matrix_2x3();
+        matrix.map_inplace(|x| *x -= 1.0);
+
+        assert_eq!(matrix.data, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_zip_map() {
+        let mask = Matrix {
+            data: vec![true, false, true, false, true, false],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_2x3()
+            .zip_map(&mask, |&x, &keep| if keep { x } else { 0.0 })
+            .unwrap();
+
+        assert_eq!(result.data, vec![1.0, 0.0, 3.0, 0.0, 5.0, 0.0]);
+    }
+
+    #[test]
+    fn test_zip_map_shape_mismatch() {
+        let result = matrix_2x3().zip_map(&Matrix::<f64>::new(3, 2), |a, b| a + b);
+
+        assert_eq!(
+            result.err(),
+            Some(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_elementwise_multiply() {
+        let result = matrix_2x3().elementwise_multiply(&matrix_2x3()).unwrap();
+        assert_eq!(result.data, vec![1.0, 4.0, 9.0, 16.0, 25.0, 36.0]);
+    }
+
+    #[test]
+    fn test_elementwise_divide() {
+        let rhs = Matrix {
+            data: vec![1.0, 4.0, 2.0, 8.0, 5.0, 3.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_2x3().elementwise_divide(&rhs).unwrap();
+        assert_eq!(result.data, vec![1.0, 0.5, 1.5, 0.5, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_elementwise_min_max() {
+        let lhs = Matrix {
+            data: vec![1, 8, -3, 4],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let rhs = Matrix {
+            data: vec![2, 5, -4, 4],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!(lhs.elementwise_min(&rhs).unwrap().data, vec![1, 5, -4, 4]);
+        assert_eq!(lhs.elementwise_max(&rhs).unwrap().data, vec![2, 8, -3, 4]);
+    }
+
+    #[test]
+    fn test_elementwise_pow() {
+        let exponents = Matrix {
+            data: vec![2.0, 0.5, 0.0, 1.0, -1.0, 2.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_2x3().elementwise_pow(&exponents).unwrap();
+        let expected = [1.0, 2.0_f64.sqrt(), 1.0, 4.0, 0.2, 36.0];
+
+        result
+            .data
+            .iter()
+            .zip(expected)
+            .for_each(|(r, e)| assert!((r - e).abs() < 1e-12));
+    }
+
+    #[test]
+    /// Verify a 1xN bias row gets added to every row of a MxN activation
+    fn test_broadcast_add_row_vector() {
+        let bias = Matrix {
+            data: vec![10.0, 20.0, 30.0],
+            row_size: 1,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_2x3().broadcast_add(&bias).unwrap();
+
+        assert_eq!(result.shape(), (2, 3));
+        assert_eq!(result.data, vec![11.0, 22.0, 33.0, 14.0, 25.0, 36.0]);
+    }
+
+    #[test]
+    fn test_broadcast_sub_column_vector() {
+        let means = Matrix {
+            data: vec![2.0, 5.0],
+            row_size: 2,
+            col_size: 1,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_2x3().broadcast_sub(&means).unwrap();
+        assert_eq!(result.data, vec![-1.0, 0.0, 1.0, -1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_broadcast_scalar_matrix() {
+        let scale = Matrix {
+            data: vec![2.0],
+            row_size: 1,
+            col_size: 1,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_2x3().elementwise_multiply(&scale).unwrap();
+        assert_eq!(result.data, vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]);
+    }
+
+    #[test]
+    fn test_broadcast_shape_mismatch() {
+        let row = Matrix::<f64>::new(1, 2);
+        let column = Matrix::<f64>::new(3, 1);
+        let other = Matrix::<f64>::new(3, 2);
+
+        for rhs in [&row, &column, &other] {
+            assert_eq!(
+                matrix_2x3().broadcast_add(rhs).err(),
+                Some(MatrixError::ShapeMismatch {
+                    left: (2, 3),
+                    right: rhs.shape()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_broadcast_column_major() {
+        let bias = Matrix {
+            data: vec![10.0, 20.0, 30.0],
+            row_size: 1,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_2x3()
+            .into_layout(Layout::ColumnMajor)
+            .broadcast_add(&bias)
+            .unwrap();
+
+        assert_eq!(result.try_get_row(0), Some(vec![11.0, 22.0, 33.0]));
+        assert_eq!(result.try_get_row(1), Some(vec![14.0, 25.0, 36.0]));
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+/// Reasons a `Matrix` operation can fail.
+///
+/// Shapes are given as (rows, columns).
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatrixError {
+    /// The shapes of the two operands aren't compatible for the operation
+    ShapeMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    /// The operation is only computable for square (M x M) matrices
+    NonSquare { shape: (usize, usize) },
+    /// The matrix is singular (or too badly conditioned to work with)
+    Singular,
+    /// The index is outside of the matrix
+    IndexOutOfBounds {
+        index: (usize, usize),
+        shape: (usize, usize),
+    },
+    /// A parameter (or the matrix itself) doesn't meet the operations requirements
+    InvalidParameter(String),
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::ShapeMismatch { left, right } => write!(
+                f,
+                "shape mismatch between a {}x{} and a {}x{} matrix",
+                left.0, left.1, right.0, right.1
+            ),
+            MatrixError::NonSquare { shape } => write!(
+                f,
+                "expected a square matrix, got a {}x{} matrix",
+                shape.0, shape.1
+            ),
+            MatrixError::Singular => write!(f, "matrix is singular"),
+            MatrixError::IndexOutOfBounds { index, shape } => write!(
+                f,
+                "index ({}, {}) is out of bounds for a {}x{} matrix",
+                index.0, index.1, shape.0, shape.1
+            ),
+            MatrixError::InvalidParameter(reason) => write!(f, "invalid parameter: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_shape_mismatch() {
+        let err = MatrixError::ShapeMismatch {
+            left: (2, 3),
+            right: (2, 5),
+        };
+        assert_eq!(
+            err.to_string(),
+            "shape mismatch between a 2x3 and a 2x5 matrix"
+        );
+    }
+
+    #[test]
+    fn test_display_index_out_of_bounds() {
+        let err = MatrixError::IndexOutOfBounds {
+            index: (4, 0),
+            shape: (2, 2),
+        };
+        assert_eq!(
+            err.to_string(),
+            "index (4, 0) is out of bounds for a 2x2 matrix"
+        );
+    }
+
+    #[test]
+    fn test_error_is_std_error() {
+        let err: Box<dyn std::error::Error> = Box::new(MatrixError::Singular);
+        assert_eq!(err.to_string(), "matrix is singular");
+    }
+}
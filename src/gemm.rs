@@ -0,0 +1,298 @@
+//! Cache blocked general matrix multiplication (GEMM) kernel shared by
+//! `Matrix::multiply` and `MatrixView::multiply`.
+
+use crate::parallel::{self, MIN_PARALLEL_LEN};
+use std::ops::{Add, Mul, Range};
+
+/// Rows of the left operand worked on at a time, every row of the block
+/// sweeps over the same packed panel of the right operand.
+const MC: usize = 64;
+/// Depth of a packed panel (rows of the right operand)
+const KC: usize = 128;
+/// Width of a packed panel (columns of the right operand),
+/// a KC x NC panel of f64's is 512KB so it stays in the L2 cache.
+const NC: usize = 512;
+/// Rows of the output updated together, so each loaded panel row is reused
+const MR: usize = 4;
+
+/// An operand of a matrix multiplication,
+/// element (row, col) lives at `row * row_stride + col * col_stride` in `data`
+pub(crate) struct Operand<'a, T> {
+    pub data: &'a [T],
+    pub row_stride: usize,
+    pub col_stride: usize,
+}
+
+impl<T> Operand<'_, T> {
+    fn get(&self, row: usize, col: usize) -> &T {
+        &self.data[row * self.row_stride + col * self.col_stride]
+    }
+}
+
+/// Multiply a MxK operand `a` by a KxN operand `b`, giving the row major MxN product.
+///
+/// NOTE: Row major operands are read in place, any other layout gets its panels
+/// packed into a contiguous row major buffer first, so the inner loop is always
+/// a straight `c += a_ip * b_row` over slices, which the compiler auto-vectorizes
+/// for f32 and f64.
+pub(crate) fn gemm<T>(
+    a: &Operand<'_, T>,
+    b: &Operand<'_, T>,
+    m: usize,
+    k: usize,
+    n: usize,
+) -> Vec<T>
+where
+    T: Default + Clone + Add<Output = T> + Mul<Output = T>,
+{
+    let mut c = vec![T::default(); m * n];
+    gemm_overwrite(a, b, k, n, &mut c);
+    c
+}
+
+/// Multiply a MxK operand `a` by a KxN operand `b`, giving the row major MxN product.
+///
+/// NOTE: With the `parallel` feature, large products are split into blocks of
+/// rows that are multiplied on separate threads.
+pub(crate) fn par_gemm<T>(
+    a: &Operand<'_, T>,
+    b: &Operand<'_, T>,
+    m: usize,
+    k: usize,
+    n: usize,
+) -> Vec<T>
+where
+    T: Default + Clone + Add<Output = T> + Mul<Output = T> + Send + Sync,
+{
+    let mut c = vec![T::default(); m * n];
+    if n == 0 {
+        return c;
+    }
+
+    // Only split off blocks of rows with enough multiply-adds to be worth a thread
+    let min_len = (MIN_PARALLEL_LEN * 16).div_ceil(k.max(1));
+    parallel::for_each_chunk_mut(&mut c, min_len, n, |start, rows| {
+        let first_row = start / n;
+        let a_rows = Operand {
+            data: &a.data[first_row * a.row_stride..],
+            row_stride: a.row_stride,
+            col_stride: a.col_stride,
+        };
+        gemm_into(&a_rows, b, k, n, rows);
+    });
+
+    c
+}
+
+/// Multiply a MxK operand `a` by a KxN operand `b`, overwriting the row major
+/// MxN buffer `c` with the product.
+///
+/// NOTE: Doesn't allocate when both operands are row major.
+pub(crate) fn gemm_overwrite<T>(
+    a: &Operand<'_, T>,
+    b: &Operand<'_, T>,
+    k: usize,
+    n: usize,
+    c: &mut [T],
+) where
+    T: Default + Clone + Add<Output = T> + Mul<Output = T>,
+{
+    c.fill(T::default());
+    if n == 0 {
+        return;
+    }
+
+    gemm_into(a, b, k, n, c);
+}
+
+/// Multiply the first `c.len() / n` rows of `a` by `b`, adding them onto `c`
+fn gemm_into<T>(a: &Operand<'_, T>, b: &Operand<'_, T>, k: usize, n: usize, c: &mut [T])
+where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    let m = c.len() / n;
+    // Only filled (and so allocated) for operands that aren't row major
+    let mut panel: Vec<T> = Vec::new();
+    let mut block: Vec<T> = Vec::new();
+
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+
+            // b[pc..pc + kc, jc..jc + nc] as row major rows, packed when it isn't already
+            let b_panel = match b.col_stride {
+                1 => Operand {
+                    data: &b.data[pc * b.row_stride + jc..],
+                    row_stride: b.row_stride,
+                    col_stride: 1,
+                },
+                _ => {
+                    panel.clear();
+                    (pc..pc + kc)
+                        .for_each(|p| panel.extend((jc..jc + nc).map(|j| b.get(p, j).clone())));
+                    Operand {
+                        data: &panel,
+                        row_stride: nc,
+                        col_stride: 1,
+                    }
+                }
+            };
+
+            for ic in (0..m).step_by(MC) {
+                let mc = MC.min(m - ic);
+
+                // a[ic..ic + mc, pc..pc + kc] as row major rows, packed when it isn't already
+                let a_block = match a.col_stride {
+                    1 => Operand {
+                        data: &a.data[ic * a.row_stride + pc..],
+                        row_stride: a.row_stride,
+                        col_stride: 1,
+                    },
+                    _ => {
+                        block.clear();
+                        (ic..ic + mc)
+                            .for_each(|i| block.extend((pc..pc + kc).map(|p| a.get(i, p).clone())));
+                        Operand {
+                            data: &block,
+                            row_stride: kc,
+                            col_stride: 1,
+                        }
+                    }
+                };
+
+                c[ic * n..(ic + mc) * n]
+                    .chunks_mut(MR * n)
+                    .enumerate()
+                    .for_each(|(idx, c_rows)| {
+                        let a_rows = Operand {
+                            data: &a_block.data[idx * MR * a_block.row_stride..],
+                            row_stride: a_block.row_stride,
+                            col_stride: 1,
+                        };
+                        match c_rows.len() == MR * n {
+                            true => micro_kernel(&a_rows, &b_panel, kc, c_rows, jc..jc + nc),
+                            false => c_rows.chunks_mut(n).enumerate().for_each(|(i, c_row)| {
+                                let a_row = &a_rows.data[i * a_rows.row_stride..][..kc];
+                                row_kernel(a_row, &b_panel, &mut c_row[jc..jc + nc])
+                            }),
+                        }
+                    });
+            }
+        }
+    }
+}
+
+/// Update the `cols` of MR rows of the output with a kc deep panel,
+/// reading each panel row once for all of them.
+fn micro_kernel<T>(
+    a_rows: &Operand<'_, T>,
+    panel: &Operand<'_, T>,
+    kc: usize,
+    c_rows: &mut [T],
+    cols: Range<usize>,
+) where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    let n = c_rows.len() / MR;
+    let nc = cols.len();
+
+    let (r0, rest) = c_rows.split_at_mut(n);
+    let (r1, rest) = rest.split_at_mut(n);
+    let (r2, r3) = rest.split_at_mut(n);
+    let (r0, r1, r2, r3) = (
+        &mut r0[cols.clone()],
+        &mut r1[cols.clone()],
+        &mut r2[cols.clone()],
+        &mut r3[cols],
+    );
+
+    (0..kc).for_each(|p| {
+        let b_row = &panel.data[p * panel.row_stride..][..nc];
+        let a0 = a_rows.get(0, p).clone();
+        let a1 = a_rows.get(1, p).clone();
+        let a2 = a_rows.get(2, p).clone();
+        let a3 = a_rows.get(3, p).clone();
+
+        (0..nc).for_each(|j| {
+            let b = &b_row[j];
+            r0[j] = r0[j].clone() + a0.clone() * b.clone();
+            r1[j] = r1[j].clone() + a1.clone() * b.clone();
+            r2[j] = r2[j].clone() + a2.clone() * b.clone();
+            r3[j] = r3[j].clone() + a3.clone() * b.clone();
+        });
+    });
+}
+
+/// Update a single row of the output with a panel, for the rows left over after `micro_kernel`
+fn row_kernel<T>(a_row: &[T], panel: &Operand<'_, T>, c_row: &mut [T])
+where
+    T: Clone + Add<Output = T> + Mul<Output = T>,
+{
+    let nc = c_row.len();
+    a_row.iter().enumerate().for_each(|(p, a_ip)| {
+        c_row
+            .iter_mut()
+            .zip(&panel.data[p * panel.row_stride..][..nc])
+            .for_each(|(c, b)| *c = c.clone() + a_ip.clone() * b.clone())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Textbook i-j-k product to check the kernel against
+    fn naive(a: &Operand<'_, i64>, b: &Operand<'_, i64>, m: usize, k: usize, n: usize) -> Vec<i64> {
+        (0..m)
+            .flat_map(|i| (0..n).map(move |j| (0..k).map(|p| a.get(i, p) * b.get(p, j)).sum()))
+            .collect()
+    }
+
+    fn row_major(data: &[i64], cols: usize) -> Operand<'_, i64> {
+        Operand {
+            data,
+            row_stride: cols,
+            col_stride: 1,
+        }
+    }
+
+    #[test]
+    /// Verify sizes that don't line up with any of the block sizes
+    fn test_gemm_ragged_blocks() {
+        let (m, k, n) = (71, 130, 517);
+        let a_data: Vec<i64> = (0..m * k).map(|x| (x % 7) as i64 - 3).collect();
+        let b_data: Vec<i64> = (0..k * n).map(|x| (x % 5) as i64 - 2).collect();
+        let (a, b) = (row_major(&a_data, k), row_major(&b_data, n));
+
+        assert_eq!(gemm(&a, &b, m, k, n), naive(&a, &b, m, k, n));
+    }
+
+    #[test]
+    fn test_gemm_column_major_operand() {
+        let (m, k, n) = (6, 5, 9);
+        let a_data: Vec<i64> = (0..m * k).map(|x| x as i64).collect();
+        let b_data: Vec<i64> = (0..k * n).map(|x| x as i64 - 20).collect();
+        let a = Operand {
+            data: &a_data,
+            row_stride: 1,
+            col_stride: m,
+        };
+        let b = Operand {
+            data: &b_data,
+            row_stride: 1,
+            col_stride: k,
+        };
+
+        assert_eq!(gemm(&a, &b, m, k, n), naive(&a, &b, m, k, n));
+    }
+
+    #[test]
+    fn test_gemm_empty_inner_dimension() {
+        let a = row_major(&[], 0);
+        let b = row_major(&[], 3);
+
+        assert_eq!(gemm(&a, &b, 2, 0, 3), vec![0; 6]);
+    }
+}
@@ -0,0 +1,242 @@
+//! Weight initializers for neural network layers.
+//!
+//! Every initializer builds a `fan_in` x `fan_out` weight `Matrix`, so a batch
+//! of inputs `x` (one sample per row) maps to `x.multiply(&w)`, and draws from
+//! the given `Rng` so a seeded `Rng` always gives back the same weights.
+
+use crate::numbers::FromF64;
+use crate::random::{Distribution, Normal, Rng, Uniform};
+use crate::{Layout, Matrix, MatrixError};
+
+impl<T: FromF64> Matrix<T> {
+    /// Xavier/Glorot uniform initialization, drawing from U(-limit, limit)
+    /// with `limit = sqrt(6 / (fan_in + fan_out))`.
+    /// NOTE: Keeps the activation variance steady for tanh/sigmoid layers.
+    pub fn xavier_uniform(
+        rng: &mut Rng,
+        fan_in: usize,
+        fan_out: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        check_fans(fan_in, fan_out)?;
+        let limit = (6.0 / (fan_in + fan_out) as f64).sqrt();
+
+        Ok(sample_weights(
+            rng,
+            &Uniform::new(-limit, limit)?,
+            fan_in,
+            fan_out,
+        ))
+    }
+
+    /// Xavier/Glorot normal initialization, drawing from N(0, std^2)
+    /// with `std = sqrt(2 / (fan_in + fan_out))`.
+    pub fn xavier_normal(
+        rng: &mut Rng,
+        fan_in: usize,
+        fan_out: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        check_fans(fan_in, fan_out)?;
+        let std = (2.0 / (fan_in + fan_out) as f64).sqrt();
+
+        Ok(sample_weights(
+            rng,
+            &Normal::new(0.0, std)?,
+            fan_in,
+            fan_out,
+        ))
+    }
+
+    /// He/Kaiming normal initialization, drawing from N(0, std^2)
+    /// with `std = sqrt(2 / fan_in)`.
+    /// NOTE: Keeps the activation variance steady for ReLU layers.
+    pub fn he_normal(
+        rng: &mut Rng,
+        fan_in: usize,
+        fan_out: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        check_fans(fan_in, fan_out)?;
+        let std = (2.0 / fan_in as f64).sqrt();
+
+        Ok(sample_weights(
+            rng,
+            &Normal::new(0.0, std)?,
+            fan_in,
+            fan_out,
+        ))
+    }
+
+    /// Orthogonal initialization (Saxe et al.), the Q factor of a gaussian
+    /// `Matrix` with its columns signed so it's uniformly distributed.
+    ///
+    /// NOTE: The columns are orthonormal when `fan_in >= fan_out`, else the rows
+    /// are, call `Matrix::scalar_multiply` on the result to apply a gain.
+    pub fn orthogonal(
+        rng: &mut Rng,
+        fan_in: usize,
+        fan_out: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        check_fans(fan_in, fan_out)?;
+        let (rows, cols) = (fan_in.max(fan_out), fan_in.min(fan_out));
+
+        let gaussian: Matrix<f64> = sample_weights(rng, &Normal::new(0.0, 1.0)?, rows, cols);
+        let qr = gaussian.qr();
+
+        // Flip each column of Q by the sign of R's diagonal, else the
+        // householder sign convention skews the distribution
+        let q: Matrix<f64> = Matrix {
+            data: (0..rows)
+                .flat_map(|row| (0..cols).map(move |col| (row, col)))
+                .map(|(row, col)| {
+                    let sign = match qr.r.data[col * cols + col] < 0.0 {
+                        true => -1.0,
+                        false => 1.0,
+                    };
+                    sign * qr.q.data[row * rows + col]
+                })
+                .collect(),
+            row_size: rows,
+            col_size: cols,
+            layout: Layout::RowMajor,
+        };
+
+        // Wide weights are the transpose of a tall orthogonal matrix
+        let q = match fan_in >= fan_out {
+            true => q,
+            false => q.into_transpose(),
+        };
+
+        Ok(Matrix {
+            data: q.row_major_data().iter().map(|&x| T::from_f64(x)).collect(),
+            row_size: fan_in,
+            col_size: fan_out,
+            layout: Layout::RowMajor,
+        })
+    }
+}
+
+/// Validity check that the weights aren't empty
+fn check_fans(fan_in: usize, fan_out: usize) -> Result<(), MatrixError> {
+    match fan_in > 0 && fan_out > 0 {
+        true => Ok(()),
+        false => Err(MatrixError::InvalidParameter(
+            "fan_in and fan_out must be non zero".to_string(),
+        )),
+    }
+}
+
+/// Draw a `rows` x `cols` `Matrix` of f64's from `distribution`, converted to `T`
+fn sample_weights<T, D>(rng: &mut Rng, distribution: &D, rows: usize, cols: usize) -> Matrix<T>
+where
+    T: FromF64,
+    D: Distribution<f64>,
+{
+    Matrix {
+        data: (0..rows * cols)
+            .map(|_| T::from_f64(distribution.sample(rng)))
+            .collect(),
+        row_size: rows,
+        col_size: cols,
+        layout: Layout::RowMajor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mean and (population) variance of the weights
+    fn moments(weights: &Matrix<f64>) -> (f64, f64) {
+        let n = weights.data.len() as f64;
+        let mean = weights.data.iter().sum::<f64>() / n;
+        let variance = weights.data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        (mean, variance)
+    }
+
+    /// Verify `m` is (close to) the identity
+    fn assert_identity(m: &Matrix<f64>) {
+        (0..m.row_size).for_each(|row| {
+            (0..m.col_size).for_each(|col| {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                let value = *m.get(row, col).unwrap();
+                assert!((value - expected).abs() < 1e-10, "({row}, {col}) = {value}");
+            })
+        });
+    }
+
+    #[test]
+    fn test_xavier_uniform() {
+        let (fan_in, fan_out) = (300, 200);
+        let w: Matrix<f64> = Matrix::xavier_uniform(&mut Rng::new(1), fan_in, fan_out).unwrap();
+
+        let limit = (6.0 / 500.0_f64).sqrt();
+        let (mean, variance) = moments(&w);
+
+        assert_eq!(w.shape(), (fan_in, fan_out));
+        assert!(w.data.iter().all(|x| (-limit..limit).contains(x)));
+        assert!(mean.abs() < 1e-3, "mean {mean}");
+        // U(-limit, limit) has a variance of limit^2 / 3 = 2 / (fan_in + fan_out)
+        assert!(
+            (variance / (2.0 / 500.0) - 1.0).abs() < 0.02,
+            "variance {variance}"
+        );
+    }
+
+    #[test]
+    fn test_xavier_normal() {
+        let w: Matrix<f64> = Matrix::xavier_normal(&mut Rng::new(2), 300, 200).unwrap();
+        let (mean, variance) = moments(&w);
+
+        assert!(mean.abs() < 1e-3, "mean {mean}");
+        assert!(
+            (variance / (2.0 / 500.0) - 1.0).abs() < 0.02,
+            "variance {variance}"
+        );
+    }
+
+    #[test]
+    fn test_he_normal() {
+        let w: Matrix<f64> = Matrix::he_normal(&mut Rng::new(3), 400, 250).unwrap();
+        let (mean, variance) = moments(&w);
+
+        assert!(mean.abs() < 1e-3, "mean {mean}");
+        assert!(
+            (variance / (2.0 / 400.0) - 1.0).abs() < 0.02,
+            "variance {variance}"
+        );
+    }
+
+    #[test]
+    /// Verify tall weights have orthonormal columns and wide weights orthonormal rows
+    fn test_orthogonal() {
+        let tall: Matrix<f64> = Matrix::orthogonal(&mut Rng::new(4), 12, 5).unwrap();
+        let wide: Matrix<f64> = Matrix::orthogonal(&mut Rng::new(5), 5, 12).unwrap();
+        let square: Matrix<f64> = Matrix::orthogonal(&mut Rng::new(6), 8, 8).unwrap();
+
+        assert_eq!(tall.shape(), (12, 5));
+        assert_eq!(wide.shape(), (5, 12));
+        assert_identity(&tall.transpose().multiply(&tall).unwrap());
+        assert_identity(&wide.multiply(&wide.transpose()).unwrap());
+        assert_identity(&square.transpose().multiply(&square).unwrap());
+    }
+
+    #[test]
+    /// Verify the initializers work for f32 weights and are reproducible
+    fn test_f32_reproducible() {
+        let a: Matrix<f32> = Matrix::he_normal(&mut Rng::new(7), 10, 4).unwrap();
+        let b: Matrix<f32> = Matrix::he_normal(&mut Rng::new(7), 10, 4).unwrap();
+        let c: Matrix<f32> = Matrix::orthogonal(&mut Rng::new(7), 10, 4).unwrap();
+
+        assert_eq!(a.data, b.data);
+        assert_eq!(c.shape(), (10, 4));
+    }
+
+    #[test]
+    fn test_zero_fan_is_an_error() {
+        let mut rng = Rng::new(8);
+
+        assert!(Matrix::<f64>::xavier_uniform(&mut rng, 0, 3).is_err());
+        assert!(Matrix::<f64>::xavier_normal(&mut rng, 3, 0).is_err());
+        assert!(Matrix::<f64>::he_normal(&mut rng, 0, 0).is_err());
+        assert!(Matrix::<f64>::orthogonal(&mut rng, 0, 3).is_err());
+    }
+}
@@ -13,7 +13,7 @@
 //! Or manually add it into your Cargo.toml:
 //! ```toml
 //! [dependencies]
-//! matrix-oxide = "0.1.2"
+//! matrix-oxide = "0.2.0"
 //! ```
 //!
 //! Usage
@@ -33,10 +33,23 @@
 //! ```
 
 pub mod activation;
+pub mod axis;
+pub mod decomposition;
+pub mod elementwise;
+pub mod error;
+mod gemm;
+pub mod init;
 pub mod matrix;
 pub mod numbers;
+mod parallel;
 pub mod random;
+mod strassen;
 pub mod vector;
+pub mod view;
 
-// expose `Matrix` at the crates root level
-pub use matrix::Matrix;
+// expose `Matrix`, its `Layout` and views, `MatrixError`, `Axis` and `Rng` at the crates root level
+pub use axis::Axis;
+pub use error::MatrixError;
+pub use matrix::{Layout, Matrix};
+pub use random::Rng;
+pub use view::{MatrixView, MatrixViewMut};
@@ -1,12 +1,118 @@
-use crate::random;
+use crate::gemm::{self, Operand};
+use crate::parallel::{self, MIN_PARALLEL_LEN};
+use crate::random::{self, Distribution, Rng, SampleUniform, Uniform};
+use crate::strassen;
+use crate::MatrixError;
+use std::borrow::Cow;
 use std::fmt::Debug;
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, AddAssign, Bound, Div, Mul, MulAssign, Neg, RangeBounds, Sub, SubAssign};
 
 /// MxN Matrix
 pub struct Matrix<T> {
     pub data: Vec<T>,
     pub row_size: usize,
     pub col_size: usize,
+    pub layout: Layout,
+}
+
+/// Order the elements of a `Matrix` are stored in `data`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Rows are contiguous, element (row, col) lives at `row * col_size + col`
+    #[default]
+    RowMajor,
+    /// Columns are contiguous, element (row, col) lives at `col * row_size + row`
+    ColumnMajor,
+}
+
+impl<T> Matrix<T> {
+    /// Get the shape of the matrix as (rows, columns)
+    pub fn shape(&self) -> (usize, usize) {
+        (self.row_size, self.col_size)
+    }
+
+    /// Get the strides of the matrix as (row stride, column stride),
+    /// element (row, col) lives at `row * row_stride + col * col_stride` in `data`
+    pub fn strides(&self) -> (usize, usize) {
+        match self.layout {
+            Layout::RowMajor => (self.col_size, 1),
+            Layout::ColumnMajor => (1, self.row_size),
+        }
+    }
+
+    /// Borrow the `Matrix` as an operand for the matrix multiplication kernel
+    pub(crate) fn operand(&self) -> Operand<'_, T> {
+        let (row_stride, col_stride) = self.strides();
+        Operand {
+            data: &self.data,
+            row_stride,
+            col_stride,
+        }
+    }
+
+    /// Position of element (row, col) in `data`
+    pub(crate) fn offset(&self, row: usize, col: usize) -> usize {
+        let (row_stride, col_stride) = self.strides();
+        row * row_stride + col * col_stride
+    }
+
+    /// (row, col) of the element at position `idx` in `data`
+    pub(crate) fn position(&self, idx: usize) -> (usize, usize) {
+        match self.layout {
+            Layout::RowMajor => (idx / self.col_size, idx % self.col_size),
+            Layout::ColumnMajor => (idx % self.row_size, idx / self.row_size),
+        }
+    }
+
+    /// Transpose the `Matrix` in O(1) by swapping its layout, without moving any data
+    pub fn into_transpose(self) -> Matrix<T> {
+        Matrix {
+            data: self.data,
+            row_size: self.col_size,
+            col_size: self.row_size,
+            layout: match self.layout {
+                Layout::RowMajor => Layout::ColumnMajor,
+                Layout::ColumnMajor => Layout::RowMajor,
+            },
+        }
+    }
+}
+impl<T: Clone> Matrix<T> {
+    /// Store the `Matrix` with the given `layout`,
+    /// only moving the data around if the layout actually changes
+    pub fn into_layout(self, layout: Layout) -> Matrix<T> {
+        if self.layout == layout {
+            return self;
+        }
+
+        let data = match layout {
+            Layout::RowMajor => self.row_major_data().into_owned(),
+            Layout::ColumnMajor => (0..self.col_size)
+                .flat_map(|col| (0..self.row_size).map(move |row| (row, col)))
+                .map(|(row, col)| self.data[self.offset(row, col)].clone())
+                .collect(),
+        };
+
+        Matrix {
+            data,
+            row_size: self.row_size,
+            col_size: self.col_size,
+            layout,
+        }
+    }
+
+    /// Borrow the data in row major order, only copying it for a column major `Matrix`
+    pub(crate) fn row_major_data(&self) -> Cow<'_, [T]> {
+        match self.layout {
+            Layout::RowMajor => Cow::Borrowed(&self.data),
+            Layout::ColumnMajor => Cow::Owned(
+                (0..self.row_size)
+                    .flat_map(|row| (0..self.col_size).map(move |col| (row, col)))
+                    .map(|(row, col)| self.data[self.offset(row, col)].clone())
+                    .collect(),
+            ),
+        }
+    }
 }
 impl<T: Default + Clone> Matrix<T> {
     /// Construct a new *non-empty* and *sized* `Matrix`
@@ -15,10 +121,13 @@ impl<T: Default + Clone> Matrix<T> {
             data: vec![T::default(); row_size * col_size],
             row_size,
             col_size,
+            layout: Layout::RowMajor,
         }
     }
 
     /// Construct a new *non-empty* and *sized* `Matrix` with random values of `T`
+    /// NOTE: Integers are drawn from a small non negative range (e.g. [0, 5,000) for `i32`)
+    /// and floats from [0, 1), use `Matrix::new_random_range` to pick the range.
     pub fn new_random(row_size: usize, col_size: usize) -> Self
     where
         T: random::Random,
@@ -28,13 +137,101 @@ impl<T: Default + Clone> Matrix<T> {
             data: random_data,
             row_size,
             col_size,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Construct a new *non-empty* and *sized* `Matrix` with random values of `T`
+    /// drawn from `rng`, so a seeded `Rng` always gives back the same `Matrix`.
+    pub fn new_random_with(rng: &mut Rng, row_size: usize, col_size: usize) -> Self
+    where
+        T: random::Random,
+    {
+        Matrix {
+            data: rng.random_vec(row_size * col_size),
+            row_size,
+            col_size,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Construct a new *non-empty* and *sized* `Matrix` with random values of `T`
+    /// uniformly drawn from `range` (e.g. `-5..5` or `0..=u8::MAX`),
+    /// returning an error for an empty or unbounded range.
+    pub fn new_random_range(
+        range: impl RangeBounds<T>,
+        row_size: usize,
+        col_size: usize,
+    ) -> Result<Self, MatrixError>
+    where
+        Uniform<T>: Distribution<T>,
+        T: SampleUniform,
+    {
+        Matrix::new_random_range_with(&mut Rng::from_entropy(), range, row_size, col_size)
+    }
+
+    /// Construct a new *non-empty* and *sized* `Matrix` with random values of `T`
+    /// uniformly drawn from `range` using `rng`, returning an error for an empty or unbounded range.
+    pub fn new_random_range_with(
+        rng: &mut Rng,
+        range: impl RangeBounds<T>,
+        row_size: usize,
+        col_size: usize,
+    ) -> Result<Self, MatrixError>
+    where
+        Uniform<T>: Distribution<T>,
+        T: SampleUniform,
+    {
+        let uniform = match (range.start_bound(), range.end_bound()) {
+            (Bound::Included(low), Bound::Excluded(high)) => {
+                Uniform::new(low.clone(), high.clone())?
+            }
+            (Bound::Included(low), Bound::Included(high)) => {
+                Uniform::new_inclusive(low.clone(), high.clone())?
+            }
+            _ => {
+                return Err(MatrixError::InvalidParameter(
+                    "random range must have an included start and an end".to_string(),
+                ))
+            }
+        };
+        Ok(Matrix::random_from_with(rng, &uniform, row_size, col_size))
+    }
+
+    /// Construct a new *non-empty* and *sized* `Matrix` with values drawn from `distribution`
+    /// (e.g. `random::Normal`), see `Matrix::random_from_with` for reproducible values.
+    pub fn random_from<D>(distribution: &D, row_size: usize, col_size: usize) -> Self
+    where
+        D: Distribution<T>,
+    {
+        Matrix::random_from_with(&mut Rng::from_entropy(), distribution, row_size, col_size)
+    }
+
+    /// Construct a new *non-empty* and *sized* `Matrix` with values drawn from `distribution`,
+    /// using `rng` so a seeded `Rng` always gives back the same `Matrix`.
+    pub fn random_from_with<D>(
+        rng: &mut Rng,
+        distribution: &D,
+        row_size: usize,
+        col_size: usize,
+    ) -> Self
+    where
+        D: Distribution<T>,
+    {
+        Matrix {
+            data: (0..row_size * col_size)
+                .map(|_| distribution.sample(rng))
+                .collect(),
+            row_size,
+            col_size,
+            layout: Layout::RowMajor,
         }
     }
 
     /// Try to get a reference to the value at a given row and column from the matrix
     pub fn get(&self, row: usize, col: usize) -> Option<&T> {
         if row < self.row_size && col < self.col_size {
-            Some(&self.data[col + row * self.col_size])
+            Some(&self.data[self.offset(row, col)])
         } else {
             None
         }
@@ -50,7 +247,8 @@ impl<T: Default + Clone> Matrix<T> {
     /// Try to get a mutable reference to the value at a given row and column from the matrix
     pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
         if row < self.row_size && col < self.col_size {
-            Some(&mut self.data[col + row * self.col_size])
+            let idx = self.offset(row, col);
+            Some(&mut self.data[idx])
         } else {
             None
         }
@@ -66,6 +264,18 @@ impl<T: Default + Clone> Matrix<T> {
         }
     }
 
+    /// Try to set a value at a given row and column in the matrix, returning
+    /// an error if the row or column is out of bounds
+    pub fn try_set(&mut self, row: usize, column: usize, value: T) -> Result<(), MatrixError> {
+        match self.set(row, column, value) {
+            true => Ok(()),
+            false => Err(MatrixError::IndexOutOfBounds {
+                index: (row, column),
+                shape: self.shape(),
+            }),
+        }
+    }
+
     /// Try to get all the values for a given column
     ///
     /// NOTE: If you pass a column value larger than the number of columns
@@ -78,7 +288,7 @@ impl<T: Default + Clone> Matrix<T> {
 
         // Iterate over all the rows grabbing a specific column each time
         let col_data: Vec<T> = (0..self.row_size)
-            .map(|row| self.data[row * self.col_size + column].clone())
+            .map(|row| self.data[self.offset(row, column)].clone())
             .collect();
 
         Some(col_data)
@@ -96,7 +306,7 @@ impl<T: Default + Clone> Matrix<T> {
 
         // Iterate over all the rows grabbing a specific column each time
         let row_data: Vec<T> = (0..self.col_size)
-            .map(|col| self.data[row * self.col_size + col].clone())
+            .map(|col| self.data[self.offset(row, col)].clone())
             .collect();
 
         Some(row_data)
@@ -109,6 +319,7 @@ impl<T: Default + Clone> Matrix<T> {
                 data: Vec::new(),
                 row_size: 0,
                 col_size: 0,
+                layout: Layout::RowMajor,
             };
         }
 
@@ -123,6 +334,7 @@ impl<T: Default + Clone> Matrix<T> {
             data,
             row_size,
             col_size,
+            layout: Layout::RowMajor,
         }
     }
 
@@ -148,17 +360,41 @@ impl<T: Default + Clone> Matrix<T> {
     }
 
     /// Perform a transpose operation (swap rows for columns and vice versa)
+    /// NOTE: This copies the data into a new row major `Matrix`,
+    /// use `Matrix::into_transpose` to transpose in O(1) instead.
     pub fn transpose(&self) -> Matrix<T> {
         Matrix {
             data: (0..self.col_size)
-                .flat_map(|col| {
-                    (0..self.row_size).map(move |row| self.data[row * self.col_size + col].clone())
-                })
+                .flat_map(|col| (0..self.row_size).map(move |row| (row, col)))
+                .map(|(row, col)| self.data[self.offset(row, col)].clone())
                 .collect(),
 
             row_size: self.col_size,
             col_size: self.row_size,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Write the transpose of the `Matrix` into `out` (a preallocated NxM `Matrix`)
+    /// instead of allocating a new one, returning an error if `out` has the wrong shape.
+    /// NOTE: `out` is overwritten in row major order, whatever its layout was.
+    pub fn transpose_into(&self, out: &mut Matrix<T>) -> Result<(), MatrixError> {
+        // Validity check that the transpose fits `out`
+        let shape = (self.col_size, self.row_size);
+        if out.shape() != shape || out.data.len() != self.data.len() {
+            return Err(MatrixError::ShapeMismatch {
+                left: out.shape(),
+                right: shape,
+            });
         }
+
+        out.layout = Layout::RowMajor;
+        out.data.iter_mut().enumerate().for_each(|(idx, value)| {
+            let (col, row) = (idx / self.row_size, idx % self.row_size);
+            *value = self.data[self.offset(row, col)].clone();
+        });
+
+        Ok(())
     }
 }
 
@@ -175,21 +411,11 @@ where
     type Output = Matrix<T>;
 
     /// Matrix addition
-    /// NOTE: the matrices you add MUST have the same dimensionality
+    /// NOTE: the matrices you add MUST have the same dimensionality, else this
+    /// panics (use `Matrix::checked_add` to handle that as an error)
     fn add(self, rhs: Self) -> Matrix<T> {
-        let data: Vec<T> = (0..self.row_size)
-            .flat_map(|row| {
-                let row_a = self.try_get_row(row).expect("Invalid row in self");
-                let row_b = rhs.try_get_row(row).expect("Invalid row in rhs");
-                row_a.into_iter().zip(row_b).map(|(a, b)| a + b)
-            })
-            .collect();
-
-        Matrix {
-            data,
-            col_size: self.col_size,
-            row_size: self.row_size,
-        }
+        self.checked_add(&rhs)
+            .unwrap_or_else(|err| panic!("Matrix addition failed: {err}"))
     }
 }
 impl<T> Matrix<T>
@@ -214,6 +440,46 @@ where
             .cloned()
             .fold(T::default(), |acc, x| acc + x)
     }
+
+    /// Perform a summation over the matrix, summing chunks of large
+    /// matrices on multiple threads with the `parallel` feature.
+    /// NOTE: Floating point sums may differ from `Matrix::sum` in the last bits,
+    /// since the chunks are added up in a different order.
+    pub fn par_sum(&self) -> T
+    where
+        T: Send + Sync,
+    {
+        parallel::map_chunks(&self.data, MIN_PARALLEL_LEN, 1, |_, chunk| {
+            chunk.iter().cloned().fold(T::default(), |acc, x| acc + x)
+        })
+        .into_iter()
+        .fold(T::default(), |acc, x| acc + x)
+    }
+
+    /// Add a matrix to another matrix, returning an error
+    /// if the matrices don't have the same dimensionality
+    pub fn checked_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_map(rhs, |a, b| a.clone() + b.clone())
+    }
+}
+impl<T> Matrix<T>
+where
+    T: Clone + Add<Output = T>,
+{
+    /// Add a matrix onto this matrix in place (without allocating), returning
+    /// an error if the matrices don't have the same dimensionality
+    pub fn checked_add_assign(&mut self, rhs: &Matrix<T>) -> Result<(), MatrixError> {
+        // Validity check that both dimensions match
+        if self.shape() != rhs.shape() {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: rhs.shape(),
+            });
+        }
+
+        self.zip_inplace(rhs, |a, b| *a = a.clone() + b.clone());
+        Ok(())
+    }
 }
 impl<T: Default + Clone + Debug> Sub for Matrix<T>
 where
@@ -222,21 +488,36 @@ where
     type Output = Matrix<T>;
 
     /// Subtract a matrix by another matrix
-    /// NOTE: the matrix you subtract by MUST have the same dimensionality
+    /// NOTE: the matrix you subtract by MUST have the same dimensionality, else
+    /// this panics (use `Matrix::checked_sub` to handle that as an error)
     fn sub(self, rhs: Self) -> Matrix<T> {
-        let data: Vec<T> = (0..self.row_size)
-            .flat_map(|row| {
-                let row_a = self.try_get_row(row).expect("Invalid row in self");
-                let row_b = rhs.try_get_row(row).expect("Invalid row in rhs");
-                row_a.into_iter().zip(row_b).map(|(a, b)| a - b)
-            })
-            .collect();
+        self.checked_sub(&rhs)
+            .unwrap_or_else(|err| panic!("Matrix subtraction failed: {err}"))
+    }
+}
+impl<T> Matrix<T>
+where
+    T: Clone + Sub<Output = T>,
+{
+    /// Subtract a matrix by another matrix, returning an error
+    /// if the matrices don't have the same dimensionality
+    pub fn checked_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.zip_map(rhs, |a, b| a.clone() - b.clone())
+    }
 
-        Matrix {
-            data,
-            row_size: self.row_size,
-            col_size: self.col_size,
+    /// Subtract a matrix from this matrix in place (without allocating),
+    /// returning an error if the matrices don't have the same dimensionality
+    pub fn checked_sub_assign(&mut self, rhs: &Matrix<T>) -> Result<(), MatrixError> {
+        // Validity check that both dimensions match
+        if self.shape() != rhs.shape() {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: rhs.shape(),
+            });
         }
+
+        self.zip_inplace(rhs, |a, b| *a = a.clone() - b.clone());
+        Ok(())
     }
 }
 impl<T: Default> Matrix<T>
@@ -246,44 +527,164 @@ where
     /// Multiply a matrix by a single number (scalar)
     /// NOTE: The scalar type MUST match the matrix type.
     pub fn scalar_multiply(&self, scalar: T) -> Matrix<T> {
-        let data = self
-            .data
-            .iter()
-            .map(|value| value.clone() * scalar.clone())
-            .collect();
+        self.map(|value| value.clone() * scalar.clone())
+    }
 
-        Matrix {
-            data,
-            row_size: self.row_size,
-            col_size: self.col_size,
-        }
+    /// Multiply a matrix by a single number (scalar), splitting large
+    /// matrices over multiple threads with the `parallel` feature.
+    /// NOTE: The scalar type MUST match the matrix type.
+    pub fn par_scalar_multiply(&self, scalar: T) -> Matrix<T>
+    where
+        T: Send + Sync,
+    {
+        self.par_map(|value| value.clone() * scalar.clone())
     }
 
     /// Multiply `Matrix` with another `Matrix` using standard matrix multiplication
     /// NOTE: The matrices inner dimensions MUST match else returns None
+    /// NOTE: Uses a cache blocked kernel that works with either layout,
+    /// and auto-vectorizes for f32 and f64.
     pub fn multiply(&self, multiplier: &Matrix<T>) -> Option<Matrix<T>> {
         // Validity check for the matrices inner dimensions
         if self.col_size != multiplier.row_size {
             return None;
         }
 
-        let data: Vec<T> = (0..self.row_size)
-            .flat_map(|i| {
-                (0..multiplier.col_size).map(move |j| {
-                    (0..self.col_size)
-                        .map(|k| {
-                            self.data[i * self.col_size + k].clone()
-                                * multiplier.data[k * multiplier.col_size + j].clone()
-                        })
-                        .fold(T::default(), |acc, x| acc + x)
-                })
-            })
-            .collect();
+        let data = gemm::gemm(
+            &self.operand(),
+            &multiplier.operand(),
+            self.row_size,
+            self.col_size,
+            multiplier.col_size,
+        );
+
+        Some(Matrix {
+            data,
+            col_size: multiplier.col_size,
+            row_size: self.row_size,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Multiply `Matrix` with another `Matrix` using standard matrix multiplication,
+    /// splitting large products into blocks of rows multiplied on separate threads
+    /// with the `parallel` feature.
+    /// NOTE: The matrices inner dimensions MUST match else returns None
+    pub fn par_multiply(&self, multiplier: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Send + Sync,
+    {
+        // Validity check for the matrices inner dimensions
+        if self.col_size != multiplier.row_size {
+            return None;
+        }
+
+        let data = gemm::par_gemm(
+            &self.operand(),
+            &multiplier.operand(),
+            self.row_size,
+            self.col_size,
+            multiplier.col_size,
+        );
 
         Some(Matrix {
             data,
             col_size: multiplier.col_size,
             row_size: self.row_size,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Multiply `Matrix` with another `Matrix`, writing the product into `out`
+    /// (a preallocated MxN `Matrix`) instead of allocating a new one.
+    /// NOTE: Returns an error if the inner dimensions don't match or `out` has the wrong shape.
+    /// NOTE: `out` is overwritten in row major order, whatever its layout was.
+    /// NOTE: Doesn't allocate when both matrices are row major.
+    pub fn multiply_into(
+        &self,
+        multiplier: &Matrix<T>,
+        out: &mut Matrix<T>,
+    ) -> Result<(), MatrixError> {
+        // Validity check for the matrices inner dimensions
+        if self.col_size != multiplier.row_size {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: multiplier.shape(),
+            });
+        }
+        // Validity check that the product fits `out`
+        let shape = (self.row_size, multiplier.col_size);
+        if out.shape() != shape || out.data.len() != shape.0 * shape.1 {
+            return Err(MatrixError::ShapeMismatch {
+                left: out.shape(),
+                right: shape,
+            });
+        }
+
+        gemm::gemm_overwrite(
+            &self.operand(),
+            &multiplier.operand(),
+            self.col_size,
+            multiplier.col_size,
+            &mut out.data,
+        );
+        out.layout = Layout::RowMajor;
+
+        Ok(())
+    }
+
+    /// Multiply `Matrix` with another `Matrix` using standard matrix multiplication,
+    /// returning an error if the matrices inner dimensions don't match
+    pub fn try_multiply(&self, multiplier: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.multiply(multiplier).ok_or(MatrixError::ShapeMismatch {
+            left: self.shape(),
+            right: multiplier.shape(),
+        })
+    }
+
+    /// Size at or below which `Matrix::multiply_strassen` stops recursing and
+    /// uses the regular kernel, see the benchmarks crate for how it was picked.
+    pub const STRASSEN_CUTOFF: usize = 256;
+
+    /// Multiply `Matrix` with another `Matrix` using Strassen's algorithm,
+    /// returning an error if the matrices inner dimensions don't match.
+    ///
+    /// NOTE: Only square matrices of the same size above `Matrix::STRASSEN_CUTOFF`
+    /// use Strassen, everything else falls back to `Matrix::multiply`.
+    ///
+    /// NOTE: Strassen trades multiplications for additions, so floating point
+    /// results pick up a little more rounding error than `Matrix::multiply`.
+    pub fn multiply_strassen(&self, multiplier: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.multiply_strassen_with_cutoff(multiplier, Self::STRASSEN_CUTOFF)
+    }
+
+    /// Multiply `Matrix` with another `Matrix` using Strassen's algorithm,
+    /// recursing until the sub matrices are at most `cutoff` in size.
+    ///
+    /// NOTE: The best cutoff depends on the machine, a cutoff of 0 recurses
+    /// all the way down to 1x1 matrices.
+    pub fn multiply_strassen_with_cutoff(
+        &self,
+        multiplier: &Matrix<T>,
+        cutoff: usize,
+    ) -> Result<Matrix<T>, MatrixError> {
+        let n = self.row_size;
+        if self.shape() != (n, n) || multiplier.shape() != (n, n) || n <= cutoff {
+            return self.try_multiply(multiplier);
+        }
+
+        let data = strassen::strassen(
+            &self.row_major_data(),
+            &multiplier.row_major_data(),
+            n,
+            cutoff,
+        );
+
+        Ok(Matrix {
+            data,
+            row_size: n,
+            col_size: n,
+            layout: Layout::RowMajor,
         })
     }
 
@@ -298,7 +699,7 @@ where
         let data: Vec<T> = (0..self.row_size)
             .map(|i| {
                 (0..multiplier.len())
-                    .map(|j| self.data[i * self.col_size + j].clone() * multiplier[j].clone())
+                    .map(|j| self.data[self.offset(i, j)].clone() * multiplier[j].clone())
                     .fold(T::default(), |acc, x| acc + x)
             })
             .collect();
@@ -306,94 +707,322 @@ where
         Some(data)
     }
 
+    /// Multiply the `Matrix` by a vector, splitting the rows of large
+    /// matrices over multiple threads with the `parallel` feature.
+    /// NOTE: The vectors length MUST match the vector columns, else returns None
+    pub fn par_vector_multiply(&self, multiplier: &[T]) -> Option<Vec<T>>
+    where
+        T: Send + Sync,
+    {
+        // Validity check that the `Matrix` column size matches the vector column size
+        if self.col_size != multiplier.len() {
+            return None;
+        }
+
+        let mut data = vec![T::default(); self.row_size];
+        let min_len = MIN_PARALLEL_LEN.div_ceil(self.col_size.max(1));
+        parallel::for_each_chunk_mut(&mut data, min_len, 1, |start, chunk| {
+            chunk.iter_mut().enumerate().for_each(|(idx, value)| {
+                *value = (0..multiplier.len())
+                    .map(|j| self.data[self.offset(start + idx, j)].clone() * multiplier[j].clone())
+                    .fold(T::default(), |acc, x| acc + x)
+            })
+        });
+
+        Some(data)
+    }
+
+    /// Multiply the `Matrix` by a vector, returning an error if the vectors
+    /// length doesn't match the matrix columns
+    pub fn try_vector_multiply(&self, multiplier: &[T]) -> Result<Vec<T>, MatrixError> {
+        self.vector_multiply(multiplier)
+            .ok_or(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: (multiplier.len(), 1),
+            })
+    }
+}
+impl<T: Determinant + Clone> Matrix<T> {
     /// Compute a unique determinant for a `Matrix`
     /// NOTE: Only computable for square (M x M) matrices.
     /// NOTE: The determinant is 0 for a `Matrix` with rank r < M (non-invertable).
+    ///
+    /// NOTE: This is O(n^3), using partial pivoting for floats and fraction-free
+    /// elimination (Bareiss algorithm) for integers, see `Determinant`.
     pub fn determinant(&self) -> Option<T> {
         // Validity check that it's a square matrix
-        if self.col_size != self.row_size {
+        if self.col_size != self.row_size || self.row_size == 0 {
             return None;
         }
 
-        // Base case for recursion: 1x1 matrix
-        if self.row_size == 1 {
-            return Some(self.data[0].clone());
-        }
-
-        if let Some(first_row) = self.try_get_row(0) {
-            let determinant =
-                first_row
-                    .iter()
-                    .enumerate()
-                    .fold(T::default(), |acc, (col_idx, item)| {
-                        let sub_matrix = self.sub_matrix(0, col_idx);
-
-                        if let Some(sub_determinant) = sub_matrix.determinant() {
-                            // Alternate between addition and subtraction
-                            // operations every iteration
-                            if col_idx % 2 == 0 {
-                                acc + item.clone() * sub_determinant
-                            } else {
-                                acc - item.clone() * sub_determinant
-                            }
-                        } else {
-                            T::default()
-                        }
-                    });
+        // det(A^T) = det(A), so a column major `Matrix` can be read as is
+        Some(T::determinant(self.data.clone(), self.row_size))
+    }
 
-            Some(determinant)
-        } else {
-            None
+    /// Compute a unique determinant for a `Matrix`, returning an error
+    /// for a non square or empty `Matrix`
+    pub fn try_determinant(&self) -> Result<T, MatrixError> {
+        if self.row_size != self.col_size {
+            return Err(MatrixError::NonSquare {
+                shape: self.shape(),
+            });
         }
+
+        self.determinant().ok_or(MatrixError::InvalidParameter(
+            "the determinant of an empty matrix is undefined".to_string(),
+        ))
     }
 }
 impl<T> Matrix<T>
 where
-    T: Default + Clone + From<f64>,
+    T: Clone + Div<Output = T>,
 {
-    /// Create an identity matrix of given size
-    pub fn identity(size: usize) -> Matrix<T> {
-        let data = (0..size * size)
-            .map(|i| {
-                if i % (size + 1) == 0 {
-                    T::from(1.0)
-                } else {
-                    T::default()
-                }
-            })
-            .collect();
-
-        Matrix {
-            data,
-            row_size: size,
-            col_size: size,
-        }
+    /// Divide a matrix by a single number (scalar)
+    /// NOTE: The scalar type MUST match the matrix type.
+    pub fn scalar_divide(&self, scalar: T) -> Matrix<T> {
+        self.map(|value| value.clone() / scalar.clone())
     }
 }
-impl<T> Matrix<T>
+impl<T> Add<&Matrix<T>> for &Matrix<T>
 where
-    T: Default + Clone + Mul<Output = T> + Add<Output = T> + Into<f64>,
+    T: Default + Clone + Add<Output = T>,
 {
-    /// Compute the frobenius norm of a `Matrix`
-    pub fn frobenius_norm(&self) -> f64 {
-        let sum_of_squares: f64 = self
-            .data
-            .iter()
-            .map(|val| {
-                let val_f64: f64 = val.clone().into();
-                val_f64 * val_f64
-            })
-            .fold(f64::default(), |acc, x| acc + x);
+    type Output = Matrix<T>;
 
-        sum_of_squares.sqrt()
+    /// Matrix addition without consuming either matrix
+    /// NOTE: the matrices you add MUST have the same dimensionality
+    fn add(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.checked_add(rhs)
+            .unwrap_or_else(|err| panic!("Matrix addition failed: {err}"))
     }
 }
-impl<T> Matrix<T>
+impl<T> Sub<&Matrix<T>> for &Matrix<T>
 where
-    T: Copy
-        + PartialOrd
-        + Default
-        + From<f64>
+    T: Clone + Sub<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Subtract a matrix by another matrix without consuming either matrix
+    /// NOTE: the matrix you subtract by MUST have the same dimensionality
+    fn sub(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|err| panic!("Matrix subtraction failed: {err}"))
+    }
+}
+impl<T> Mul<&Matrix<T>> for &Matrix<T>
+where
+    T: Default + Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Standard matrix multiplication without consuming either matrix
+    /// NOTE: The matrices inner dimensions MUST match
+    fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.try_multiply(rhs)
+            .unwrap_or_else(|err| panic!("Matrix multiplication failed: {err}"))
+    }
+}
+impl<T> Mul for Matrix<T>
+where
+    T: Default + Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Standard matrix multiplication
+    /// NOTE: The matrices inner dimensions MUST match
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        &self * &rhs
+    }
+}
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Clone + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiply a matrix by a single number (scalar), reusing its storage
+    fn mul(mut self, scalar: T) -> Matrix<T> {
+        self *= scalar;
+        self
+    }
+}
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: Default + Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiply a matrix by a single number (scalar)
+    fn mul(self, scalar: T) -> Matrix<T> {
+        self.scalar_multiply(scalar)
+    }
+}
+impl<T> Div<T> for Matrix<T>
+where
+    T: Clone + Div<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Divide a matrix by a single number (scalar), reusing its storage
+    fn div(mut self, scalar: T) -> Matrix<T> {
+        self.data
+            .iter_mut()
+            .for_each(|value| *value = value.clone() / scalar.clone());
+        self
+    }
+}
+impl<T> Div<T> for &Matrix<T>
+where
+    T: Clone + Div<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Divide a matrix by a single number (scalar)
+    fn div(self, scalar: T) -> Matrix<T> {
+        self.scalar_divide(scalar)
+    }
+}
+impl<T> Neg for Matrix<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Negate every element of a matrix, reusing its storage
+    fn neg(mut self) -> Matrix<T> {
+        self.data
+            .iter_mut()
+            .for_each(|value| *value = -value.clone());
+        self
+    }
+}
+impl<T> Neg for &Matrix<T>
+where
+    T: Clone + Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Negate every element of a matrix
+    fn neg(self) -> Matrix<T> {
+        Matrix {
+            data: self.data.iter().map(|value| -value.clone()).collect(),
+            row_size: self.row_size,
+            col_size: self.col_size,
+            layout: self.layout,
+        }
+    }
+}
+impl<T> AddAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Clone + Add<Output = T>,
+{
+    /// In place matrix addition
+    /// NOTE: the matrices you add MUST have the same dimensionality
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        self.checked_add_assign(rhs)
+            .unwrap_or_else(|err| panic!("Matrix addition failed: {err}"));
+    }
+}
+impl<T> AddAssign for Matrix<T>
+where
+    T: Clone + Add<Output = T>,
+{
+    /// In place matrix addition
+    /// NOTE: the matrices you add MUST have the same dimensionality
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
+impl<T> SubAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Clone + Sub<Output = T>,
+{
+    /// In place matrix subtraction
+    /// NOTE: the matrix you subtract by MUST have the same dimensionality
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        self.checked_sub_assign(rhs)
+            .unwrap_or_else(|err| panic!("Matrix subtraction failed: {err}"));
+    }
+}
+impl<T> SubAssign for Matrix<T>
+where
+    T: Clone + Sub<Output = T>,
+{
+    /// In place matrix subtraction
+    /// NOTE: the matrix you subtract by MUST have the same dimensionality
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+impl<T> MulAssign<T> for Matrix<T>
+where
+    T: Clone + Mul<Output = T>,
+{
+    /// In place multiplication by a single number (scalar)
+    fn mul_assign(&mut self, scalar: T) {
+        self.data
+            .iter_mut()
+            .for_each(|value| *value = value.clone() * scalar.clone());
+    }
+}
+impl<T> MulAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Default + Clone + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    /// Standard matrix multiplication, storing the product in the left matrix
+    /// NOTE: The matrices inner dimensions MUST match
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = &*self * rhs;
+    }
+}
+impl<T> Matrix<T>
+where
+    T: Default + Clone + From<f64>,
+{
+    /// Create an identity matrix of given size
+    pub fn identity(size: usize) -> Matrix<T> {
+        let data = (0..size * size)
+            .map(|i| {
+                if i % (size + 1) == 0 {
+                    T::from(1.0)
+                } else {
+                    T::default()
+                }
+            })
+            .collect();
+
+        Matrix {
+            data,
+            row_size: size,
+            col_size: size,
+            layout: Layout::RowMajor,
+        }
+    }
+}
+impl<T> Matrix<T>
+where
+    T: Default + Clone + Mul<Output = T> + Add<Output = T> + Into<f64>,
+{
+    /// Compute the frobenius norm of a `Matrix`
+    pub fn frobenius_norm(&self) -> f64 {
+        let sum_of_squares: f64 = self
+            .data
+            .iter()
+            .map(|val| {
+                let val_f64: f64 = val.clone().into();
+                val_f64 * val_f64
+            })
+            .fold(f64::default(), |acc, x| acc + x);
+
+        sum_of_squares.sqrt()
+    }
+}
+impl<T> Matrix<T>
+where
+    T: Copy
+        + PartialOrd
+        + Default
+        + From<f64>
         + Into<f64>
         + Sub<Output = T>
         + Add<Output = T>
@@ -405,59 +1034,58 @@ where
     /// NOTE: Only computable for square (M x M) matrices.
     /// NOTE: Only computable for a `Matrix` where r = M (full rank).
     pub fn inverse(&self) -> Option<Matrix<T>> {
-        // Validity check that it's a square matrix
-        if self.row_size != self.col_size {
-            return None;
-        }
+        self.lu()?.inverse()
+    }
 
-        let n = self.row_size;
-        let epsilon = T::from(1e-10);
-
-        // Regular matrix
-        let mut a = self.data.clone();
-        // Inverted matrix
-        let mut b = Matrix::<T>::identity(n).data;
-
-        for i in 0..n {
-            let pivot = (i..n).fold(i, |acc, j| {
-                match a[j * n + i].abs() > a[acc * n + i].abs() {
-                    true => j,
-                    false => acc,
-                }
-            });
+    /// Solve the linear system `Ax = b` for `x`, where `A` is the `Matrix`
+    /// NOTE: Only computable for square (M x M) matrices.
+    /// NOTE: The vectors length MUST match the matrix size, else returns None
+    /// NOTE: Returns None for a singular (or badly conditioned) `Matrix`.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        self.lu()?.solve(b)
+    }
 
-            // Validity check that it's not a singular matrix
-            if a[pivot * n + i].abs() < epsilon {
-                return None;
-            }
+    /// Solve the linear system `AX = B` for `X`, where `A` is the `Matrix` and
+    /// each column of `B` is a separate right hand side.
+    /// NOTE: Only computable for square (M x M) matrices.
+    /// NOTE: The row size of `B` MUST match the matrix size, else returns None
+    /// NOTE: Returns None for a singular (or badly conditioned) `Matrix`.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        self.lu()?.solve_matrix(b)
+    }
 
-            if pivot != i {
-                (0..n).for_each(|k| {
-                    a.swap(i * n + k, pivot * n + k);
-                    b.swap(i * n + k, pivot * n + k);
-                });
-            }
+    /// Compute the inverse of a `Matrix`, returning an error
+    /// for a non square or singular `Matrix`
+    pub fn try_inverse(&self) -> Result<Matrix<T>, MatrixError> {
+        self.try_lu()?.inverse().ok_or(MatrixError::Singular)
+    }
 
-            let pivot_val = a[i * n + i];
-            (0..n).for_each(|k| {
-                a[i * n + k] = a[i * n + k] / pivot_val;
-                b[i * n + k] = b[i * n + k] / pivot_val;
+    /// Solve the linear system `Ax = b` for `x`, where `A` is the `Matrix`, returning
+    /// an error for a non square or singular `Matrix` or a wrongly sized vector
+    pub fn try_solve(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let lu = self.try_lu()?;
+        if b.len() != self.row_size {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: (b.len(), 1),
             });
+        }
 
-            (0..n).filter(|&j| j != i).for_each(|j| {
-                let factor = a[j * n + i];
-                (0..n).for_each(|k| {
-                    a[j * n + k] = a[j * n + k] - factor * a[i * n + k];
-                    b[j * n + k] = b[j * n + k] - factor * b[i * n + k];
-                })
-            })
+        lu.solve(b).ok_or(MatrixError::Singular)
+    }
+
+    /// Solve the linear system `AX = B` for `X`, where `A` is the `Matrix`, returning
+    /// an error for a non square or singular `Matrix` or a wrongly sized `B`
+    pub fn try_solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        let lu = self.try_lu()?;
+        if b.row_size != self.row_size {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: b.shape(),
+            });
         }
 
-        Some(Matrix {
-            data: b,
-            row_size: n,
-            col_size: n,
-        })
+        lu.solve_matrix(b).ok_or(MatrixError::Singular)
     }
 }
 
@@ -485,6 +1113,142 @@ impl Abs for f32 {
     }
 }
 
+/// Element types a `Matrix` determinant can be computed for in O(n^3)
+///
+/// NOTE: Floats use gaussian elimination with partial pivoting, integers use
+/// fraction-free elimination (Bareiss algorithm) so the result stays exact.
+/// Unsigned integers up to 64 bits are eliminated as `i128`, as the minors of a
+/// `Matrix` can be negative even when its determinant isn't.
+pub trait Determinant: Sized {
+    /// Compute the determinant of the NxN (N > 0) matrix stored row by row in `data`
+    fn determinant(data: Vec<Self>, n: usize) -> Self;
+}
+
+/// Implements Determinant for floating point types (gaussian elimination with partial pivoting)
+macro_rules! determinant_float {
+    ($($float:ty),*) => {$(
+        impl Determinant for $float {
+            fn determinant(mut a: Vec<Self>, n: usize) -> Self {
+                let mut determinant = 1.0;
+
+                for i in 0..n {
+                    let pivot = (i..n).fold(i, |acc, j| {
+                        match a[j * n + i].abs() > a[acc * n + i].abs() {
+                            true => j,
+                            false => acc,
+                        }
+                    });
+
+                    // A column of zeros means the matrix is singular
+                    if a[pivot * n + i] == 0.0 {
+                        return 0.0;
+                    }
+
+                    if pivot != i {
+                        (0..n).for_each(|k| a.swap(i * n + k, pivot * n + k));
+                        determinant = -determinant;
+                    }
+
+                    let pivot_val = a[i * n + i];
+                    determinant *= pivot_val;
+                    (i + 1..n).for_each(|j| {
+                        let factor = a[j * n + i] / pivot_val;
+                        (i + 1..n).for_each(|k| a[j * n + k] -= factor * a[i * n + k]);
+                    });
+                }
+
+                determinant
+            }
+        }
+    )*};
+}
+determinant_float!(f32, f64);
+
+/// Implements Determinant for integer types (Bareiss algorithm), along with the
+/// type to eliminate in (wide enough for the negative minors of unsigned types).
+macro_rules! determinant_int {
+    ($($int:ty => $wide:ty),*) => {$(
+        impl Determinant for $int {
+            fn determinant(a: Vec<Self>, n: usize) -> Self {
+                let determinant = bareiss(a.into_iter().map(<$wide>::from).collect(), n);
+                <$int>::try_from(determinant).expect("determinant out of range for the element type")
+            }
+        }
+    )*};
+}
+determinant_int!(
+    i8 => i8, i16 => i16, i32 => i32, i64 => i64, i128 => i128,
+    u8 => i128, u16 => i128, u32 => i128, u64 => i128, u128 => u128
+);
+
+/// Compute the determinant of the NxN (N > 0) matrix stored row by row in `a`
+/// with fraction-free gaussian elimination (Bareiss algorithm).
+///
+/// NOTE: Every division is exact and every intermediate value is a minor of
+/// the matrix, so this only overflows if some minor does.
+fn bareiss<T>(mut a: Vec<T>, n: usize) -> T
+where
+    T: Default
+        + Clone
+        + PartialOrd
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    let zero = T::default();
+    let magnitude = |x: T| {
+        if x < T::default() {
+            T::default() - x
+        } else {
+            x
+        }
+    };
+
+    let mut negate = false;
+    // Pivot of the previous step, every entry of the next step divides by it
+    let mut prev_pivot: Option<T> = None;
+
+    for i in 0..n - 1 {
+        let pivot = (i..n).fold(i, |acc, j| {
+            match magnitude(a[j * n + i].clone()) > magnitude(a[acc * n + i].clone()) {
+                true => j,
+                false => acc,
+            }
+        });
+
+        // A column of zeros means the matrix is singular
+        if a[pivot * n + i] == zero {
+            return zero;
+        }
+
+        if pivot != i {
+            (0..n).for_each(|k| a.swap(i * n + k, pivot * n + k));
+            negate = !negate;
+        }
+
+        let pivot_val = a[i * n + i].clone();
+        (i + 1..n).for_each(|j| {
+            (i + 1..n).for_each(|k| {
+                let value = a[j * n + k].clone() * pivot_val.clone()
+                    - a[j * n + i].clone() * a[i * n + k].clone();
+                a[j * n + k] = match &prev_pivot {
+                    Some(prev) => value / prev.clone(),
+                    None => value,
+                };
+            });
+            a[j * n + i] = zero.clone();
+        });
+        prev_pivot = Some(pivot_val);
+    }
+
+    let determinant = a[n * n - 1].clone();
+    match negate {
+        true => zero - determinant,
+        false => determinant,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -502,6 +1266,7 @@ mod tests {
             data: vec![1, 2, 3, 4],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         let vector = vec![5, 6];
 
@@ -516,18 +1281,21 @@ mod tests {
             data: vec![1, 2, 3, 4],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let matrix_b = Matrix::<i32> {
             data: vec![2, 0, 1, 2],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected = Matrix::<i32> {
             data: vec![4, 4, 10, 8],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         let result = matrix_a.multiply(&matrix_b).unwrap();
         assert_eq!(result.data, expected.data);
@@ -539,6 +1307,7 @@ mod tests {
             data: vec![1, 2, 3, 4],
             col_size: 2,
             row_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected: i32 = 5;
@@ -552,6 +1321,7 @@ mod tests {
             data: vec![1, 2, 3, 4],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected: Vec<i32> = vec![1, 4];
@@ -565,12 +1335,14 @@ mod tests {
             data: vec![1, 2, 3, 4],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected = Matrix::<i32> {
             data: vec![2, 4, 6, 8],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         let result = matrix.scalar_multiply(2);
 
@@ -583,17 +1355,20 @@ mod tests {
             data: vec![1, 2, 3, 4, 5, 6],
             row_size: 2,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
         let matrix_b = Matrix::<i32> {
             data: vec![6, 5, 4, 3, 2, 1],
             row_size: 2,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
 
         let expected = Matrix::<i32> {
             data: vec![-5, -3, -1, 1, 3, 5],
             row_size: 2,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
         let result = matrix_a - matrix_b;
         assert_eq!(result.data, expected.data);
@@ -605,17 +1380,20 @@ mod tests {
             data: vec![1, 2, 3, 4],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         let matrix_b = Matrix::<i32> {
             data: vec![4, 3, 2, 1],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected = Matrix::<i32> {
             data: vec![5, 5, 5, 5],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let result = matrix_a + matrix_b;
@@ -681,6 +1459,7 @@ mod tests {
             data: vec![7],
             row_size: 1,
             col_size: 1,
+            layout: Layout::RowMajor,
         };
         assert_eq!(matrix.determinant(), Some(7));
     }
@@ -691,6 +1470,7 @@ mod tests {
             data: vec![1, 2, 3, 4],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         assert_eq!(matrix.determinant(), Some(-2));
     }
@@ -701,17 +1481,159 @@ mod tests {
             data: vec![3, 2, 1, 0, 1, 4, 5, 6, 0],
             row_size: 3,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
         let expected = -37;
         assert_eq!(matrix.determinant(), Some(expected));
     }
 
+    #[test]
+    /// Verify a zero leading entry gets pivoted around
+    fn determinant_needs_pivoting() {
+        let matrix = Matrix {
+            data: vec![0, 1, 2, 1, 0, 3, 4, -3, 8],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        assert_eq!(matrix.determinant(), Some(-2));
+    }
+
+    #[test]
+    fn determinant_of_singular_matrix() {
+        let matrix = Matrix {
+            data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        assert_eq!(matrix.determinant(), Some(0));
+    }
+
+    #[test]
+    /// Verify integer determinants stay exact for matrices far past
+    /// what cofactor expansion can handle, using `L * U` with known diagonals.
+    fn determinant_of_large_integer_matrix() {
+        let n = 16;
+        let l = Matrix::<i64> {
+            data: (0..n * n)
+                .map(|i| match (i / n).cmp(&(i % n)) {
+                    std::cmp::Ordering::Greater => ((i * 7) % 5) as i64 - 2,
+                    std::cmp::Ordering::Equal => 1,
+                    std::cmp::Ordering::Less => 0,
+                })
+                .collect(),
+            row_size: n,
+            col_size: n,
+            layout: Layout::RowMajor,
+        };
+        let u = Matrix::<i64> {
+            data: (0..n * n)
+                .map(|i| match (i / n).cmp(&(i % n)) {
+                    std::cmp::Ordering::Less => ((i * 3) % 7) as i64 - 3,
+                    std::cmp::Ordering::Equal => {
+                        if (i / n) % 4 == 0 {
+                            -2
+                        } else {
+                            1
+                        }
+                    }
+                    std::cmp::Ordering::Greater => 0,
+                })
+                .collect(),
+            row_size: n,
+            col_size: n,
+            layout: Layout::RowMajor,
+        };
+
+        let matrix = l.multiply(&u).unwrap();
+        assert_eq!(matrix.determinant(), Some(16));
+    }
+
+    #[test]
+    /// Verify the integer and float eliminations agree, in either layout
+    fn determinant_integer_matches_float() {
+        let n = 8;
+        let matrix = Matrix::<i64> {
+            data: (0..n * n).map(|i| ((i * 37) % 11) as i64 - 5).collect(),
+            row_size: n,
+            col_size: n,
+            layout: Layout::RowMajor,
+        };
+        let floats = matrix.map(|&x| x as f64);
+
+        let expected = matrix.determinant().unwrap();
+        assert!(expected != 0);
+        assert!((floats.determinant().unwrap() / expected as f64 - 1.0).abs() < 1e-10);
+        assert_eq!(matrix.into_transpose().determinant(), Some(expected));
+    }
+
+    #[test]
+    /// Verify unsigned matrices work even when the elimination goes negative
+    fn determinant_of_unsigned_matrix() {
+        let matrix = Matrix::<u32> {
+            data: vec![2, 1, 1, 2],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        assert_eq!(matrix.determinant(), Some(3));
+
+        // Rows (1, 2, 0), (0, 1, 3), (4, 0, 1) have negative minors but a positive determinant
+        let matrix = Matrix::<u8> {
+            data: vec![1, 2, 0, 0, 1, 3, 4, 0, 1],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        assert_eq!(matrix.determinant(), Some(25));
+    }
+
+    #[test]
+    fn determinant_of_large_float_matrix() {
+        let n = 60;
+        let mut matrix = Matrix::<f64>::identity(n).scalar_multiply(2.0);
+        // Filling the strictly upper triangle doesn't change the determinant
+        (0..n).for_each(|row| {
+            (row + 1..n).for_each(|col| {
+                matrix.set(row, col, (row + col) as f64);
+            })
+        });
+        // Move the last row to the top, a cyclic shift of n rows has sign (-1)^(n-1)
+        let mut rows: Vec<Vec<f64>> = (0..n).map(|r| matrix.try_get_row(r).unwrap()).collect();
+        rows.rotate_right(1);
+        matrix.data = rows.into_iter().flatten().collect();
+
+        let expected = -(2.0_f64.powi(n as i32));
+        let result = matrix.determinant().unwrap();
+        assert!(((result - expected) / expected).abs() < 1e-10);
+    }
+
+    #[test]
+    /// Verify well conditioned random float matrices match their LU decomposition
+    fn determinant_of_random_float_matrix() {
+        let mut rng = Rng::new(3);
+        [20, 40, 100].into_iter().for_each(|n| {
+            let matrix = Matrix::<f64>::new_random_with(&mut rng, n, n)
+                + Matrix::identity(n).scalar_multiply(2.0);
+
+            let expected = matrix.lu().unwrap().determinant();
+            let result = matrix.determinant().unwrap();
+            assert!(((result - expected) / expected).abs() < 1e-10, "n = {n}");
+
+            let floats = matrix.map(|&x| x as f32);
+            let result = floats.determinant().unwrap() as f64;
+            assert!(((result - expected) / expected).abs() < 1e-3, "n = {n}");
+        });
+    }
+
     #[test]
     fn determinant_non_square_matrix() {
         let matrix = Matrix {
             data: vec![1, 2, 3, 4, 5, 6],
             row_size: 2,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
         assert_eq!(matrix.determinant(), None);
     }
@@ -722,6 +1644,7 @@ mod tests {
             data: vec![1, 2, 3, 4],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected: f64 = 5.477225575051661;
@@ -735,6 +1658,7 @@ mod tests {
             data: vec![1.0, 2.0, 3.0, 4.0],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected: f64 = 5.477225575051661;
@@ -748,6 +1672,7 @@ mod tests {
             data: vec![4.0, 7.0, 2.0, 6.0],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let expected_inverse = vec![0.6, -0.7, -0.2, 0.4];
@@ -762,6 +1687,7 @@ mod tests {
             data: vec![1.0, 2.0, 3.0, 0.0, 1.0, 4.0, 5.0, 6.0, 0.0],
             row_size: 3,
             col_size: 3,
+            layout: Layout::RowMajor,
         };
 
         let expected_inverse = vec![-24.0, 18.0, 5.0, 20.0, -15.0, -4.0, -5.0, 4.0, 1.0];
@@ -784,6 +1710,7 @@ mod tests {
             data: vec![1.0, 2.0, 2.0, 4.0],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
 
         let result = matrix.inverse();
@@ -797,6 +1724,7 @@ mod tests {
             data: vec![2.0],
             row_size: 1,
             col_size: 1,
+            layout: Layout::RowMajor,
         };
 
         let expected_inverse = vec![0.5];
@@ -806,9 +1734,89 @@ mod tests {
     }
 
     #[test]
-    /// Test that a one by one matrix only has one value
-    fn test_random_1x1() {
-        let result: Matrix<f64> = Matrix::new_random(1, 1);
+    fn test_solve_3x3() {
+        let matrix = Matrix {
+            data: vec![2.0, 1.0, -1.0, -3.0, -1.0, 2.0, -2.0, 1.0, 2.0],
+            row_size: 3,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.solve(&[8.0, -11.0, -3.0]).unwrap();
+
+        assert!(approx_equal(&result, &[2.0, 3.0, -1.0], 1e-10));
+    }
+
+    #[test]
+    /// Verify solving matches going through the inverse
+    fn test_solve_matches_inverse() {
+        let matrix = Matrix {
+            data: vec![4.0, 7.0, 2.0, 6.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let b = vec![1.0, 0.0];
+
+        let expected = matrix.inverse().unwrap().vector_multiply(&b).unwrap();
+        let result = matrix.solve(&b).unwrap();
+
+        assert!(approx_equal(&result, &expected, 1e-10));
+    }
+
+    #[test]
+    fn test_solve_matrix() {
+        let matrix = Matrix {
+            data: vec![4.0, 7.0, 2.0, 6.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let b = Matrix {
+            data: vec![1.0, 11.0, 0.0, 8.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix.solve_matrix(&b).unwrap();
+
+        assert_eq!((result.row_size, result.col_size), (2, 2));
+        assert!(approx_equal(&result.data, &[0.6, 1.0, -0.2, 1.0], 1e-10));
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let matrix = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert!(matrix.solve(&[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_solve_invalid_dimensions() {
+        let non_square = Matrix {
+            data: vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        let square = Matrix::<f64>::identity(2);
+        let b = Matrix::<f64>::identity(3);
+
+        assert!(non_square.solve(&[1.0, 2.0]).is_none());
+        assert!(square.solve(&[1.0, 2.0, 3.0]).is_none());
+        assert!(square.solve_matrix(&b).is_none());
+    }
+
+    #[test]
+    /// Test that a one by one matrix only has one value
+    fn test_random_1x1() {
+        let result: Matrix<f64> = Matrix::new_random(1, 1);
         assert_eq!(result.data.len(), 1);
     }
 
@@ -845,6 +1853,7 @@ mod tests {
             data: vec![1.0, 2.0, 3.0, 4.0],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         assert_eq!(matrix.sum(), 10.0);
     }
@@ -855,6 +1864,7 @@ mod tests {
             data: vec![-1.0, -2.0, -3.0, -4.0],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         assert_eq!(matrix.sum(), -10.0);
     }
@@ -865,7 +1875,594 @@ mod tests {
             data: vec![-1.0, 2.0, -3.0, 4.0],
             row_size: 2,
             col_size: 2,
+            layout: Layout::RowMajor,
         };
         assert_eq!(matrix.sum(), 2.0);
     }
+
+    #[test]
+    fn test_try_set_out_of_bounds() {
+        let mut matrix = Matrix::<i32>::new(2, 2);
+
+        assert_eq!(matrix.try_set(1, 1, 5), Ok(()));
+        assert_eq!(matrix.data, vec![0, 0, 0, 5]);
+        assert_eq!(
+            matrix.try_set(2, 0, 5),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (2, 0),
+                shape: (2, 2)
+            })
+        );
+    }
+
+    #[test]
+    /// Verify a seeded `Rng` gives back the same `Matrix` every time
+    fn test_new_random_with_seed() {
+        let a: Matrix<f64> = Matrix::new_random_with(&mut Rng::new(7), 4, 3);
+        let b: Matrix<f64> = Matrix::new_random_with(&mut Rng::new(7), 4, 3);
+
+        assert_eq!(a.shape(), (4, 3));
+        assert_eq!(a.data, b.data);
+    }
+
+    #[test]
+    fn test_random_from() {
+        let uniform = random::Uniform::new(-1.0, 1.0).unwrap();
+
+        let a: Matrix<f64> = Matrix::random_from_with(&mut Rng::new(3), &uniform, 5, 4);
+        let b: Matrix<f64> = Matrix::random_from_with(&mut Rng::new(3), &uniform, 5, 4);
+        let c: Matrix<f64> = Matrix::random_from(&uniform, 5, 4);
+
+        assert_eq!(a.shape(), (5, 4));
+        assert_eq!(a.data, b.data);
+        assert!(c.data.iter().all(|x| (-1.0..1.0).contains(x)));
+    }
+
+    #[test]
+    fn test_new_random_range() {
+        let a = Matrix::<i32>::new_random_range(-5..5, 10, 10).unwrap();
+        let b = Matrix::<f32>::new_random_range_with(&mut Rng::new(1), 2.0..3.0, 4, 4).unwrap();
+        let c = Matrix::<u8>::new_random_range_with(&mut Rng::new(1), 250..u8::MAX, 4, 4).unwrap();
+        let d =
+            Matrix::<u8>::new_random_range_with(&mut Rng::new(1), 250..=u8::MAX, 10, 10).unwrap();
+
+        assert!(a.data.iter().all(|x| (-5..5).contains(x)));
+        assert!(b.data.iter().all(|x| (2.0..3.0).contains(x)));
+        assert!(c.data.iter().all(|x| (250..u8::MAX).contains(x)));
+        assert!(d.data.iter().all(|&x| x >= 250) && d.data.contains(&u8::MAX));
+        assert!(Matrix::<i64>::new_random_range(3..3, 2, 2).is_err());
+        assert!(Matrix::<i64>::new_random_range(3..=3, 2, 2).is_ok());
+        assert!(Matrix::<i64>::new_random_range(3.., 2, 2).is_err());
+        assert!(Matrix::<f64>::new_random_range(..1.0, 2, 2).is_err());
+    }
+
+    #[test]
+    /// Verify `new_random` works for every numeric type
+    fn test_new_random_numeric_types() {
+        assert!(Matrix::<f32>::new_random(5, 5)
+            .data
+            .iter()
+            .all(|x| (0.0..1.0).contains(x)));
+        assert_eq!(Matrix::<i8>::new_random(2, 3).data.len(), 6);
+        assert_eq!(Matrix::<i16>::new_random(2, 3).data.len(), 6);
+        assert_eq!(Matrix::<i128>::new_random(2, 3).data.len(), 6);
+        assert_eq!(Matrix::<u16>::new_random(2, 3).data.len(), 6);
+        assert_eq!(Matrix::<u32>::new_random(2, 3).data.len(), 6);
+        assert_eq!(Matrix::<u64>::new_random(2, 3).data.len(), 6);
+        assert_eq!(Matrix::<u128>::new_random(2, 3).data.len(), 6);
+    }
+
+    #[test]
+    /// Verify back to back calls don't give the same `Matrix`
+    fn test_new_random_differs() {
+        let a: Matrix<i64> = Matrix::new_random(8, 8);
+        let b: Matrix<i64> = Matrix::new_random(8, 8);
+
+        assert_ne!(a.data, b.data);
+    }
+
+    #[test]
+    fn test_try_multiply_shape_mismatch() {
+        let matrix_a = Matrix::<i32>::new(2, 3);
+        let matrix_b = Matrix::<i32>::new(2, 3);
+
+        assert_eq!(
+            matrix_a.try_multiply(&matrix_b).err(),
+            Some(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 3)
+            })
+        );
+        assert!(matrix_a.try_multiply(&matrix_b.transpose()).is_ok());
+    }
+
+    #[test]
+    /// Verify Strassen agrees with the regular kernel on f64's, including odd sizes
+    fn test_multiply_strassen_accuracy() {
+        [64, 100, 129].into_iter().for_each(|n| {
+            let a: Matrix<f64> = Matrix::new_random(n, n);
+            let b: Matrix<f64> = Matrix::new_random(n, n);
+
+            let expected = a.multiply(&b).unwrap();
+            let result = a.multiply_strassen_with_cutoff(&b, 16).unwrap();
+
+            let scale = expected
+                .data
+                .iter()
+                .fold(1.0_f64, |acc, x| acc.max(x.abs()));
+            expected.data.iter().zip(&result.data).for_each(|(e, r)| {
+                assert!((e - r).abs() <= 1e-12 * scale, "n = {n}: {e} != {r}");
+            });
+        });
+    }
+
+    #[test]
+    fn test_multiply_strassen_column_major() {
+        let a = Matrix {
+            data: (0..36).collect::<Vec<i64>>(),
+            row_size: 6,
+            col_size: 6,
+            layout: Layout::ColumnMajor,
+        };
+        let b = Matrix::<i64>::new_random(6, 6);
+
+        assert_eq!(
+            a.multiply_strassen_with_cutoff(&b, 1).unwrap().data,
+            a.multiply(&b).unwrap().data
+        );
+    }
+
+    #[test]
+    /// Verify non square matrices fall back to the regular kernel and mismatches error
+    fn test_multiply_strassen_fallback() {
+        let a = Matrix::<i32>::new(2, 3);
+        let b = Matrix::<i32>::new(3, 4);
+
+        assert_eq!(a.multiply_strassen(&b).unwrap().shape(), (2, 4));
+        assert_eq!(
+            a.multiply_strassen(&a).err(),
+            Some(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    /// Verify `multiply_into` overwrites whatever was in `out` with the product
+    fn test_multiply_into() {
+        let a = column_major_2x3();
+        let b = column_major_2x3().into_transpose();
+        let mut out = Matrix {
+            data: vec![9; 4],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::ColumnMajor,
+        };
+
+        a.multiply_into(&b, &mut out).unwrap();
+
+        assert_eq!(out.data, a.multiply(&b).unwrap().data);
+        assert_eq!(out.layout, Layout::RowMajor);
+    }
+
+    #[test]
+    /// Verify the threaded variants match the sequential ones on matrices big enough to be split
+    fn test_par_variants() {
+        let a = Matrix {
+            data: (0..256 * 128).map(|x| x % 17 - 8).collect::<Vec<i64>>(),
+            row_size: 256,
+            col_size: 128,
+            layout: Layout::RowMajor,
+        };
+        let b = Matrix {
+            data: (0..128 * 256).map(|x| x % 11 - 5).collect::<Vec<i64>>(),
+            row_size: 128,
+            col_size: 256,
+            layout: Layout::RowMajor,
+        }
+        .into_layout(Layout::ColumnMajor);
+        let vector: Vec<i64> = (0..128).collect();
+
+        assert_eq!(
+            a.par_multiply(&b).unwrap().data,
+            a.multiply(&b).unwrap().data
+        );
+        assert!(a.par_multiply(&a).is_none());
+        assert_eq!(a.par_sum(), a.sum());
+        assert_eq!(a.par_scalar_multiply(3).data, a.scalar_multiply(3).data);
+        assert_eq!(a.par_vector_multiply(&vector), a.vector_multiply(&vector));
+        assert!(a.par_vector_multiply(&vector[1..]).is_none());
+    }
+
+    #[test]
+    fn test_multiply_into_wrong_shape() {
+        let a = Matrix::<i32>::new(2, 3);
+        let b = Matrix::<i32>::new(3, 4);
+        let mut out = Matrix::<i32>::new(2, 3);
+
+        assert_eq!(
+            a.multiply_into(&b, &mut out),
+            Err(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 4)
+            })
+        );
+        assert_eq!(
+            a.multiply_into(&a, &mut out),
+            Err(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_transpose_into() {
+        let matrix = column_major_2x3();
+        let mut out = Matrix::<i32>::new(3, 2);
+
+        matrix.transpose_into(&mut out).unwrap();
+
+        assert_eq!(out.data, matrix.transpose().data);
+        assert_eq!(
+            matrix.transpose_into(&mut Matrix::new(2, 3)),
+            Err(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (3, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_add_assign() {
+        let mut matrix = column_major_2x3();
+        let row_major = column_major_2x3().into_layout(Layout::RowMajor);
+
+        matrix.checked_add_assign(&row_major).unwrap();
+        assert_eq!(matrix.try_get_row(1), Some(vec![8, 10, 12]));
+
+        matrix.checked_sub_assign(&row_major).unwrap();
+        assert_eq!(matrix.try_get_row(1), Some(vec![4, 5, 6]));
+
+        assert!(matrix.checked_add_assign(&Matrix::new(3, 2)).is_err());
+        assert!(matrix.checked_sub_assign(&Matrix::new(3, 2)).is_err());
+    }
+
+    #[test]
+    fn test_try_vector_multiply_shape_mismatch() {
+        let matrix = Matrix::<i32>::new(2, 3);
+
+        assert_eq!(
+            matrix.try_vector_multiply(&[1, 2]),
+            Err(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 1)
+            })
+        );
+    }
+
+    #[test]
+    fn test_try_determinant_errors() {
+        let non_square = Matrix::<i32>::new(2, 3);
+        let empty = Matrix::<i32>::new(0, 0);
+
+        assert_eq!(
+            non_square.try_determinant(),
+            Err(MatrixError::NonSquare { shape: (2, 3) })
+        );
+        assert!(matches!(
+            empty.try_determinant(),
+            Err(MatrixError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_try_inverse_errors() {
+        let singular = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let non_square = Matrix::<f64>::new(2, 3);
+
+        assert_eq!(singular.try_inverse().err(), Some(MatrixError::Singular));
+        assert_eq!(
+            non_square.try_inverse().err(),
+            Some(MatrixError::NonSquare { shape: (2, 3) })
+        );
+    }
+
+    #[test]
+    fn test_try_solve_errors() {
+        let singular = Matrix {
+            data: vec![1.0, 2.0, 2.0, 4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let identity = Matrix::<f64>::identity(2);
+
+        assert_eq!(singular.try_solve(&[1.0, 2.0]), Err(MatrixError::Singular));
+        assert_eq!(
+            identity.try_solve(&[1.0, 2.0, 3.0]),
+            Err(MatrixError::ShapeMismatch {
+                left: (2, 2),
+                right: (3, 1)
+            })
+        );
+        assert_eq!(
+            identity.try_solve_matrix(&Matrix::<f64>::identity(3)).err(),
+            Some(MatrixError::ShapeMismatch {
+                left: (2, 2),
+                right: (3, 3)
+            })
+        );
+        assert_eq!(identity.try_solve(&[1.0, 2.0]), Ok(vec![1.0, 2.0]));
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let matrix_a = Matrix::<i32> {
+            data: vec![1, 2, 3, 4, 5, 6],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+        let matrix_b = Matrix::<i32> {
+            data: vec![6, 5, 4, 3, 2, 1],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_a.checked_add(&matrix_b).unwrap();
+        assert_eq!(result.data, vec![7, 7, 7, 7, 7, 7]);
+        assert_eq!(result.shape(), (2, 3));
+    }
+
+    #[test]
+    /// Verify a column mismatch is an error instead of silently truncating
+    fn test_checked_add_column_mismatch() {
+        let matrix_a = Matrix::<i32>::new(2, 3);
+        let matrix_b = Matrix::<i32>::new(2, 5);
+
+        assert_eq!(
+            matrix_a.checked_add(&matrix_b).err(),
+            Some(MatrixError::ShapeMismatch {
+                left: (2, 3),
+                right: (2, 5)
+            })
+        );
+    }
+
+    #[test]
+    fn test_checked_sub() {
+        let matrix_a = Matrix::<i32> {
+            data: vec![1, 2, 3, 4],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let matrix_b = Matrix::<i32> {
+            data: vec![4, 3, 2, 1],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let result = matrix_a.checked_sub(&matrix_b).unwrap();
+        assert_eq!(result.data, vec![-3, -1, 1, 3]);
+    }
+
+    #[test]
+    fn test_checked_sub_row_mismatch() {
+        let matrix_a = Matrix::<i32>::new(3, 3);
+        let matrix_b = Matrix::<i32>::new(2, 3);
+
+        assert_eq!(
+            matrix_a.checked_sub(&matrix_b).err(),
+            Some(MatrixError::ShapeMismatch {
+                left: (3, 3),
+                right: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "shape mismatch between a 2x3 and a 2x5 matrix")]
+    fn test_addition_shape_mismatch_panics() {
+        let _ = Matrix::<i32>::new(2, 3) + Matrix::<i32>::new(2, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "shape mismatch between a 3x3 and a 2x3 matrix")]
+    fn test_subtraction_shape_mismatch_panics() {
+        let _ = Matrix::<i32>::new(3, 3) - Matrix::<i32>::new(2, 3);
+    }
+
+    #[test]
+    /// Verify reference operators leave both operands usable
+    fn test_reference_operators() {
+        let matrix_a = Matrix::<i32> {
+            data: vec![1, 2, 3, 4],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let matrix_b = Matrix::<i32> {
+            data: vec![2, 0, 1, 2],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!((&matrix_a + &matrix_b).data, vec![3, 2, 4, 6]);
+        assert_eq!((&matrix_a - &matrix_b).data, vec![-1, 2, 2, 2]);
+        assert_eq!((&matrix_a * &matrix_b).data, vec![4, 4, 10, 8]);
+        assert_eq!((matrix_a * matrix_b).data, vec![4, 4, 10, 8]);
+    }
+
+    #[test]
+    #[should_panic(expected = "shape mismatch between a 2x3 and a 2x3 matrix")]
+    fn test_reference_multiplication_shape_mismatch_panics() {
+        let matrix = Matrix::<i32>::new(2, 3);
+        let _ = &matrix * &matrix;
+    }
+
+    #[test]
+    fn test_scalar_operators() {
+        let matrix = Matrix::<f64> {
+            data: vec![1.0, 2.0, 3.0, 4.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        assert_eq!((&matrix * 2.0).data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((&matrix / 2.0).data, vec![0.5, 1.0, 1.5, 2.0]);
+        assert_eq!((-&matrix).data, vec![-1.0, -2.0, -3.0, -4.0]);
+        assert_eq!(matrix.scalar_divide(4.0).data, vec![0.25, 0.5, 0.75, 1.0]);
+
+        let scaled = (matrix * 3.0) / 1.5;
+        assert_eq!(scaled.data, vec![2.0, 4.0, 6.0, 8.0]);
+        assert_eq!((-scaled).data, vec![-2.0, -4.0, -6.0, -8.0]);
+    }
+
+    #[test]
+    fn test_compound_assignment_operators() {
+        let mut matrix = Matrix::<i32> {
+            data: vec![1, 2, 3, 4],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let other = Matrix::<i32> {
+            data: vec![1, 1, 1, 1],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        matrix += &other;
+        assert_eq!(matrix.data, vec![2, 3, 4, 5]);
+
+        matrix -= &other;
+        assert_eq!(matrix.data, vec![1, 2, 3, 4]);
+
+        matrix *= 2;
+        assert_eq!(matrix.data, vec![2, 4, 6, 8]);
+
+        matrix *= &other;
+        assert_eq!(matrix.data, vec![6, 6, 14, 14]);
+
+        matrix += Matrix::new(2, 2);
+        matrix -= other;
+        assert_eq!(matrix.data, vec![5, 5, 13, 13]);
+    }
+
+    #[test]
+    #[should_panic(expected = "shape mismatch between a 2x2 and a 3x2 matrix")]
+    fn test_add_assign_shape_mismatch_panics() {
+        let mut matrix = Matrix::<i32>::new(2, 2);
+        matrix += &Matrix::new(3, 2);
+    }
+
+    fn column_major_2x3() -> Matrix<i32> {
+        // [[1, 2, 3], [4, 5, 6]] stored column by column
+        Matrix {
+            data: vec![1, 4, 2, 5, 3, 6],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::ColumnMajor,
+        }
+    }
+
+    #[test]
+    /// Verify `into_transpose` only swaps the layout and leaves the data alone
+    fn test_into_transpose() {
+        let matrix = Matrix {
+            data: vec![1, 2, 3, 4, 5, 6],
+            row_size: 2,
+            col_size: 3,
+            layout: Layout::RowMajor,
+        };
+
+        let transposed = matrix.into_transpose();
+
+        assert_eq!(transposed.shape(), (3, 2));
+        assert_eq!(transposed.layout, Layout::ColumnMajor);
+        assert_eq!(transposed.data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(transposed.try_get_row(2), Some(vec![3, 6]));
+        assert_eq!(transposed.into_transpose().layout, Layout::RowMajor);
+    }
+
+    #[test]
+    fn test_column_major_access() {
+        let mut matrix = column_major_2x3();
+
+        assert_eq!(matrix.strides(), (1, 2));
+        assert_eq!(matrix.get(0, 2), Some(&3));
+        assert_eq!(matrix.try_get_row(1), Some(vec![4, 5, 6]));
+        assert_eq!(matrix.try_get_column(1), Some(vec![2, 5]));
+        assert_eq!(matrix.transpose().data, vec![1, 4, 2, 5, 3, 6]);
+
+        matrix.set(1, 0, 40);
+        assert_eq!(matrix.data, vec![1, 40, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn test_into_layout() {
+        let row_major = column_major_2x3().into_layout(Layout::RowMajor);
+        assert_eq!(row_major.data, vec![1, 2, 3, 4, 5, 6]);
+
+        let column_major = row_major.into_layout(Layout::ColumnMajor);
+        assert_eq!(column_major.data, vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    /// Verify every mix of layouts gives the same product
+    fn test_multiply_mixed_layouts() {
+        let a = column_major_2x3();
+        let b = column_major_2x3().into_transpose();
+        let expected = vec![14, 32, 32, 77];
+
+        assert_eq!(a.multiply(&b).unwrap().data, expected);
+        assert_eq!(
+            a.into_layout(Layout::RowMajor)
+                .multiply(&b.into_layout(Layout::RowMajor))
+                .unwrap()
+                .data,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_add_mixed_layouts() {
+        let row_major = column_major_2x3().into_layout(Layout::RowMajor);
+
+        let mut sum = column_major_2x3().checked_add(&row_major).unwrap();
+        assert_eq!(sum.try_get_row(0), Some(vec![2, 4, 6]));
+
+        sum -= &row_major;
+        assert_eq!(sum.layout, Layout::ColumnMajor);
+        assert_eq!(sum.try_get_row(1), Some(vec![4, 5, 6]));
+    }
+
+    #[test]
+    fn test_column_major_inverse() {
+        let matrix = Matrix {
+            data: vec![4.0, 7.0, 2.0, 6.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        }
+        .into_layout(Layout::ColumnMajor);
+
+        let inverse = matrix.inverse().unwrap();
+
+        assert!(approx_equal(&inverse.data, &[0.6, -0.7, -0.2, 0.4], 1e-10));
+        assert!((matrix.determinant().unwrap() - 10.0).abs() < 1e-10);
+    }
 }
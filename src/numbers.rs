@@ -1,3 +1,5 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
 /// Marker trait for categorizing the set of all number types
 /// and enforcing type constraints.
 pub trait Numeric: AsF64 {}
@@ -100,3 +102,187 @@ impl AsF64 for f64 {
         *self
     }
 }
+
+/// Convert an f64 to a floating point number.
+/// NOTE: This is lossy (rounds to nearest) for f32.
+pub trait FromF64 {
+    fn from_f64(value: f64) -> Self;
+}
+impl FromF64 for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+impl FromF64 for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+/// Complex number of the form `re + im * i`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+impl<T> Complex<T> {
+    /// Construct a new `Complex` number from its real and imaginary parts
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+}
+impl<T: Copy + Neg<Output = T>> Complex<T> {
+    /// Compute the complex conjugate (`re - im * i`)
+    pub fn conj(&self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+}
+impl<T: Copy + Add<Output = T> + Mul<Output = T>> Complex<T> {
+    /// Compute the squared magnitude (`re^2 + im^2`)
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+impl<T: AsF64> Complex<T> {
+    /// Compute the magnitude (absolute value)
+    pub fn norm(&self) -> f64 {
+        self.re.as_f64().hypot(self.im.as_f64())
+    }
+}
+impl<T: Add<Output = T>> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Self) -> Complex<T> {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+impl<T: Sub<Output = T>> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Self) -> Complex<T> {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Self) -> Complex<T> {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+impl<T> Div for Complex<T>
+where
+    T: Copy
+        + Default
+        + PartialOrd
+        + Neg<Output = T>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>,
+{
+    type Output = Complex<T>;
+
+    /// NOTE: Uses Smith's algorithm, dividing through by the larger part of `rhs`
+    /// first, so `c^2 + d^2` never gets computed (and can't overflow or underflow).
+    fn div(self, rhs: Self) -> Complex<T> {
+        let magnitude = |x: T| match x < T::default() {
+            true => -x,
+            false => x,
+        };
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+
+        match magnitude(d) <= magnitude(c) {
+            true => {
+                let ratio = d / c;
+                let denominator = c + d * ratio;
+                Complex::new((a + b * ratio) / denominator, (b - a * ratio) / denominator)
+            }
+            false => {
+                let ratio = c / d;
+                let denominator = c * ratio + d;
+                Complex::new((a * ratio + b) / denominator, (b * ratio - a) / denominator)
+            }
+        }
+    }
+}
+impl<T: Neg<Output = T>> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+/// Create a `Complex` number with no imaginary part from an f64.
+impl<T: From<f64> + Default> From<f64> for Complex<T> {
+    fn from(value: f64) -> Self {
+        Complex::new(T::from(value), T::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Layout, Matrix};
+
+    #[test]
+    fn test_complex_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert!(((a * b) / b - a).norm() < 1e-15);
+        assert_eq!(-a, Complex::new(-1.0, -2.0));
+        assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+    }
+
+    #[test]
+    fn test_complex_norm() {
+        let z = Complex::new(3.0, -4.0);
+        assert_eq!(z.norm_sqr(), 25.0);
+        assert_eq!(z.norm(), 5.0);
+    }
+
+    #[test]
+    /// Verify division doesn't overflow or underflow for very large or small operands
+    fn test_complex_division_extreme_scales() {
+        let huge = Complex::new(1e300, 1e300);
+        let tiny = Complex::new(1e-300, -1e-300);
+        let one = Complex::new(1.0, 0.0);
+
+        assert_eq!(huge / huge, one);
+        assert_eq!(tiny / tiny, one);
+        assert_eq!(huge / Complex::new(0.0, 1e300), Complex::new(1.0, -1.0));
+        assert_eq!(tiny / Complex::new(2e-300, 0.0), Complex::new(0.5, -0.5));
+        assert_eq!(one / Complex::new(0.0, 2.0), Complex::new(0.0, -0.5));
+    }
+
+    #[test]
+    /// Verify a complex `Matrix` works with the existing `Matrix` operations
+    fn test_complex_matrix_operations() {
+        let i = Complex::new(0.0, 1.0);
+        let one = Complex::new(1.0, 0.0);
+        let matrix = Matrix {
+            data: vec![one, i, -i, one],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let squared = matrix.multiply(&matrix).unwrap();
+        let transposed = matrix.transpose();
+        let identity = Matrix::<Complex<f64>>::identity(2);
+
+        assert_eq!(squared.data, vec![one + one, i + i, -(i + i), one + one]);
+        assert_eq!(transposed.data, vec![one, -i, i, one]);
+        assert_eq!(
+            identity.data,
+            vec![one, Complex::default(), Complex::default(), one]
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Work splitting for the `par_*` methods (e.g. `Matrix::par_map`, `Matrix::par_multiply`).
+//!
+//! With the optional `parallel` feature, large operations are split into contiguous
+//! chunks that run on std scoped threads (one per available core). Without it the
+//! `par_*` methods run on the current thread. They require `Send`/`Sync` either way,
+//! so enabling the feature never changes what compiles.
+
+/// Fewest elements worth handing to another thread
+pub(crate) const MIN_PARALLEL_LEN: usize = 1 << 15;
+
+/// Length of each chunk when splitting `len` items over the available threads,
+/// at least `min_len` long and a multiple of `multiple` (e.g. a whole number of rows).
+#[cfg(feature = "parallel")]
+fn chunk_len(len: usize, min_len: usize, multiple: usize) -> usize {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let multiple = multiple.max(1);
+
+    let chunk = len.div_ceil(threads).max(min_len).max(1);
+    chunk.div_ceil(multiple) * multiple
+}
+
+/// Run `f` over contiguous chunks of `data` (each a multiple of `multiple` long),
+/// passing in the index of the chunks first element.
+///
+/// NOTE: Chunks only go to other threads with the `parallel` feature,
+/// and only when each chunk gets at least `min_len` elements.
+pub(crate) fn for_each_chunk_mut<T, F>(data: &mut [T], min_len: usize, multiple: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        let chunk = chunk_len(data.len(), min_len, multiple);
+        if chunk < data.len() {
+            std::thread::scope(|scope| {
+                let f = &f;
+                data.chunks_mut(chunk).enumerate().for_each(|(idx, part)| {
+                    scope.spawn(move || f(idx * chunk, part));
+                });
+            });
+            return;
+        }
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = (min_len, multiple);
+
+    f(0, data)
+}
+
+/// Map contiguous chunks of `data` (each a multiple of `multiple` long) with `f`,
+/// passing in the index of the chunks first element, and collect the results in order.
+///
+/// NOTE: Chunks only go to other threads with the `parallel` feature,
+/// and only when each chunk gets at least `min_len` elements.
+pub(crate) fn map_chunks<T, R, F>(data: &[T], min_len: usize, multiple: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(usize, &[T]) -> R + Sync,
+{
+    #[cfg(feature = "parallel")]
+    {
+        let chunk = chunk_len(data.len(), min_len, multiple);
+        if chunk < data.len() {
+            return std::thread::scope(|scope| {
+                let f = &f;
+                let handles: Vec<_> = data
+                    .chunks(chunk)
+                    .enumerate()
+                    .map(|(idx, part)| scope.spawn(move || f(idx * chunk, part)))
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| {
+                        handle
+                            .join()
+                            .unwrap_or_else(|err| std::panic::resume_unwind(err))
+                    })
+                    .collect()
+            });
+        }
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = (min_len, multiple);
+
+    vec![f(0, data)]
+}
+
+/// Join the results of `map_chunks` back into one `Vec`,
+/// without copying anything when there was only a single chunk.
+pub(crate) fn concat<U>(mut chunks: Vec<Vec<U>>) -> Vec<U> {
+    match chunks.len() {
+        1 => chunks.pop().unwrap_or_default(),
+        _ => chunks.into_iter().flatten().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Verify every element is visited once, with the right starting index
+    fn test_for_each_chunk_mut() {
+        let mut data = vec![0; 100_003];
+
+        for_each_chunk_mut(&mut data, 1000, 7, |start, chunk| {
+            assert_eq!(start % 7, 0);
+            chunk
+                .iter_mut()
+                .enumerate()
+                .for_each(|(idx, x)| *x += start + idx);
+        });
+
+        assert!(data.iter().enumerate().all(|(idx, &x)| x == idx));
+    }
+
+    #[test]
+    fn test_map_chunks_keeps_order() {
+        let data: Vec<u64> = (0..100_000).collect();
+
+        let sums = map_chunks(&data, 1000, 1, |_, chunk| chunk.iter().sum::<u64>());
+        let starts = map_chunks(&data, 1000, 1, |start, chunk| (start, chunk[0]));
+
+        assert_eq!(sums.iter().sum::<u64>(), data.iter().sum());
+        assert!(starts.iter().all(|&(start, first)| start as u64 == first));
+    }
+
+    #[test]
+    fn test_small_input_stays_in_one_chunk() {
+        let data = [1, 2, 3];
+        assert_eq!(
+            map_chunks(&data, MIN_PARALLEL_LEN, 1, |_, c| c.len()),
+            vec![3]
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_chunk_len_rounds_to_multiple() {
+        let chunk = chunk_len(1000, 10, 64);
+        assert_eq!(chunk % 64, 0);
+        assert!(chunk >= 10);
+    }
+}
@@ -1,57 +1,806 @@
+use crate::MatrixError;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-/// Simple Pseudo Random Number Generator
-fn linear_congruential_generator(seed: &mut u64) -> u64 {
-    const A: u64 = 1664525;
-    const C: u64 = 1013904223;
-    const M: u64 = 1 << 32;
+/// Seedable Pseudo Random Number Generator, the same seed
+/// always gives back the same sequence of values.
+///
+/// NOTE: This is xoshiro256** (Blackman & Vigna), it has a 2^256 - 1 period
+/// and passes BigCrush, but it's NOT cryptographically secure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// Create a new `Rng` from an explicit seed (reproducible)
+    /// NOTE: The seed is expanded into the full state with SplitMix64, so
+    /// similar seeds (e.g. 1 and 2) still give unrelated sequences.
+    pub fn new(seed: u64) -> Self {
+        let mut seed = seed;
+        Rng {
+            state: std::array::from_fn(|_| splitmix64(&mut seed)),
+        }
+    }
+
+    /// Create a new `Rng` seeded from the current time and the hashers
+    /// random keys, so every call gives a different sequence.
+    pub fn from_entropy() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_nanos() as u64);
+
+        // Every `RandomState` gets fresh keys, so 2 calls within the same clock tick still differ
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(nanos);
+        Rng::new(hasher.finish())
+    }
+
+    /// Generate the next raw value in the sequence
+    pub fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.state;
+        let result = s1.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        let t = *s1 << 17;
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+        *s2 ^= t;
+        *s3 = s3.rotate_left(45);
+
+        result
+    }
+
+    /// Generate a uniformly distributed f64 in [0, 1)
+    /// NOTE: Uses the top 53 bits, so every value is a multiple of 2^-53.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Generate a (pseudo)random value of `T`
+    pub fn random<T: Random>(&mut self) -> T {
+        T::random(self)
+    }
 
-    *seed = (*seed).wrapping_mul(A).wrapping_add(C) % M;
-    *seed
+    /// Generate a (pseudo)random `Vec<T>`
+    pub fn random_vec<T: Random>(&mut self, n: usize) -> Vec<T> {
+        (0..n).map(|_| T::random(self)).collect()
+    }
+
+    /// Draw a single value from a distribution
+    pub fn sample<T, D: Distribution<T>>(&mut self, distribution: &D) -> T {
+        distribution.sample(self)
+    }
+
+    /// Generate a uniformly distributed integer in [0, bound), without the
+    /// bias of `next_u64() % bound` (Lemire's multiply and reject).
+    /// NOTE: `bound` MUST be non zero.
+    fn below(&mut self, bound: u64) -> u64 {
+        // Products landing in the first `u64::MAX % bound` values of a slot are rejected
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let product = self.next_u64() as u128 * bound as u128;
+            if (product as u64) >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+
+    /// Generate a uniformly distributed integer in [0, bound), `bound` being non zero
+    fn below_u128(&mut self, bound: u128) -> u128 {
+        if let Ok(bound) = u64::try_from(bound) {
+            return self.below(bound) as u128;
+        }
+
+        // Reject values past the last whole multiple of `bound`
+        let zone = u128::MAX - (u128::MAX - bound + 1) % bound;
+        loop {
+            let value = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+            if value <= zone {
+                return value % bound;
+            }
+        }
+    }
+}
+
+/// SplitMix64, used to expand a single seed into the state of a `Rng`
+fn splitmix64(seed: &mut u64) -> u64 {
+    *seed = seed.wrapping_add(0x9E3779B97F4A7C15);
+    let mut z = *seed;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
 }
 
 /// Generate a (pseudo)random `Vec<T>`
+/// NOTE: This is seeded with `Rng::from_entropy`, use `Rng::random_vec`
+/// with an explicitly seeded `Rng` for reproducible values.
 pub fn gen_rand_vec<T: Random>(n: usize) -> Vec<T> {
-    // Seed is current time (secs) since the unix epoch
-    let start = SystemTime::now();
-    let duration = start
-        .duration_since(UNIX_EPOCH)
-        .expect("Oh shit broo, time went backwards!");
-    let mut seed = duration.as_secs();
-
-    (0..n).map(|_| T::random(&mut seed)).collect()
+    Rng::from_entropy().random_vec(n)
 }
 
 /// Trait for generating random values
+///
+/// NOTE: Integers are drawn from a small non negative range (e.g. [0, 5,000) for `i32`)
+/// so products and sums of random matrices don't overflow, and floats from [0, 1).
+/// Use `random::Uniform` (or `Matrix::new_random_range`) for any other range,
+/// including the full range of an integer type.
 pub trait Random {
-    fn random(seed: &mut u64) -> Self;
+    fn random(rng: &mut Rng) -> Self;
 }
-/// Implements Random trait for `f64`
+/// Implements Random trait for `f64`, uniform in [0, 1)
 impl Random for f64 {
-    fn random(seed: &mut u64) -> Self {
-        linear_congruential_generator(seed) as f64 / (u64::MAX as f64)
+    fn random(rng: &mut Rng) -> Self {
+        rng.next_f64()
     }
 }
-/// Implements Random trait for `i64`
-impl Random for i64 {
-    fn random(seed: &mut u64) -> Self {
-        // Constrain the max random number for i64 to 50,000
-        const MAX: i64 = 50_000;
-        (linear_congruential_generator(seed) % (MAX as u64)) as i64
+/// Implements Random trait for `f32`, uniform in [0, 1)
+impl Random for f32 {
+    fn random(rng: &mut Rng) -> Self {
+        // The top 24 bits, so every value is a multiple of 2^-24
+        (rng.next_u64() >> 40) as f32 * (1.0 / (1u32 << 24) as f32)
     }
 }
-/// Implements Random trait for `i32`
-impl Random for i32 {
-    fn random(seed: &mut u64) -> Self {
-        // Constrain the max random number for i32 to 5,000
-        const MAX: i64 = 5_000;
-        (linear_congruential_generator(seed) % (MAX as u64)) as i32
+/// Implements Random trait for integers, uniform in [0, bound)
+macro_rules! random_int {
+    ($($int:ty => $bound:expr),*) => {$(
+        impl Random for $int {
+            fn random(rng: &mut Rng) -> Self {
+                rng.below($bound) as $int
+            }
+        }
+    )*};
+}
+random_int!(
+    i8 => 10, i16 => 500, i32 => 5_000, i64 => 50_000, i128 => 50_000,
+    u8 => 10, u16 => 500, u32 => 5_000, u64 => 50_000, u128 => 50_000
+);
+
+/// Trait for probability distributions values of `T` can be drawn from
+pub trait Distribution<T> {
+    fn sample(&self, rng: &mut Rng) -> T;
+}
+
+/// Uniform distribution over the half open range [low, high),
+/// or the closed range [low, high] when built with `Uniform::new_inclusive`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform<T> {
+    low: T,
+    high: T,
+    inclusive: bool,
+}
+
+/// Types a `Uniform` distribution can be built over
+pub trait SampleUniform: PartialOrd + Sized {
+    /// Check if [low, high) is a range values can be sampled from
+    fn is_valid_range(low: &Self, high: &Self) -> bool {
+        low < high
+    }
+
+    /// Check if [low, high] is a range values can be sampled from
+    fn is_valid_inclusive_range(low: &Self, high: &Self) -> bool {
+        low <= high
     }
 }
-/// Implements Random trait for `u8`
-impl Random for u8 {
-    fn random(seed: &mut u64) -> Self {
-        const MAX: u8 = 10;
-        (linear_congruential_generator(seed) % (MAX as u64)) as u8
+
+impl<T: SampleUniform> Uniform<T> {
+    /// Create a uniform distribution over [low, high)
+    /// NOTE: `low` MUST be less than `high` (and both finite for floats), else returns an error
+    pub fn new(low: T, high: T) -> Result<Self, MatrixError> {
+        // Validity check for a non empty range (also rejects NaN's)
+        match T::is_valid_range(&low, &high) {
+            true => Ok(Uniform {
+                low,
+                high,
+                inclusive: false,
+            }),
+            false => Err(MatrixError::InvalidParameter(
+                "uniform range must have finite bounds with low < high".to_string(),
+            )),
+        }
+    }
+
+    /// Create a uniform distribution over [low, high], so `high` itself can be drawn
+    /// NOTE: `low` MUST be less than or equal to `high` (and both finite for floats), else returns an error
+    pub fn new_inclusive(low: T, high: T) -> Result<Self, MatrixError> {
+        // Validity check for a non empty range, a single value being fine here
+        match T::is_valid_inclusive_range(&low, &high) {
+            true => Ok(Uniform {
+                low,
+                high,
+                inclusive: true,
+            }),
+            false => Err(MatrixError::InvalidParameter(
+                "uniform range must have finite bounds with low <= high".to_string(),
+            )),
+        }
+    }
+}
+
+/// Implements Distribution for `Uniform` over integer types,
+/// along with the unsigned type of the same width to measure the range in.
+macro_rules! uniform_int {
+    ($($int:ty => $unsigned:ty),*) => {$(
+        impl SampleUniform for $int {}
+
+        impl Distribution<$int> for Uniform<$int> {
+            fn sample(&self, rng: &mut Rng) -> $int {
+                let width = self.high.wrapping_sub(self.low) as $unsigned as u128;
+                // Only [MIN, MAX] of a 128 bit type has more values than a u128 can count
+                let offset = match width.checked_add(self.inclusive as u128) {
+                    Some(bound) => rng.below_u128(bound),
+                    None => ((rng.next_u64() as u128) << 64) | rng.next_u64() as u128,
+                };
+                self.low.wrapping_add(offset as $int)
+            }
+        }
+    )*};
+}
+uniform_int!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128
+);
+
+/// Implements Distribution for `Uniform` over floating point types
+macro_rules! uniform_float {
+    ($($float:ty),*) => {$(
+        impl SampleUniform for $float {
+            fn is_valid_range(low: &Self, high: &Self) -> bool {
+                low < high && low.is_finite() && high.is_finite()
+            }
+
+            fn is_valid_inclusive_range(low: &Self, high: &Self) -> bool {
+                low <= high && low.is_finite() && high.is_finite()
+            }
+        }
+
+        impl Distribution<$float> for Uniform<$float> {
+            fn sample(&self, rng: &mut Rng) -> $float {
+                // Rounding can land just outside the range (e.g. on an excluded `high`),
+                // so draw again when it does (u = 0 always gives `low`, so this stops
+                // after a couple of draws at most)
+                loop {
+                    let u = rng.next_f64() as $float;
+                    // Weighting both ends instead of using `high - low`, which
+                    // overflows for ranges wider than the largest float
+                    let value = self.low * (1.0 - u) + self.high * u;
+                    if (self.low..self.high).contains(&value) || (self.inclusive && value == self.high) {
+                        return value;
+                    }
+                }
+            }
+        }
+    )*};
+}
+uniform_float!(f32, f64);
+
+/// Normal (gaussian) distribution with a mean and standard deviation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    mean: f64,
+    std: f64,
+}
+
+impl Normal {
+    /// Create a normal distribution N(mean, std^2)
+    /// NOTE: `std` MUST be finite and non negative, else returns an error
+    pub fn new(mean: f64, std: f64) -> Result<Self, MatrixError> {
+        // Validity check for the parameters
+        match mean.is_finite() && std.is_finite() && std >= 0.0 {
+            true => Ok(Normal { mean, std }),
+            false => Err(MatrixError::InvalidParameter(
+                "normal distribution needs a finite mean and a finite, non negative std"
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// Draw a sample using the Box-Muller transform
+    fn sample_f64(&self, rng: &mut Rng) -> f64 {
+        // 1 - [0, 1) is (0, 1], so the log never sees a 0
+        let radius = (-2.0 * (1.0 - rng.next_f64()).ln()).sqrt();
+        let angle = 2.0 * std::f64::consts::PI * rng.next_f64();
+
+        self.mean + self.std * radius * angle.cos()
+    }
+}
+impl Distribution<f64> for Normal {
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        self.sample_f64(rng)
+    }
+}
+impl Distribution<f32> for Normal {
+    fn sample(&self, rng: &mut Rng) -> f32 {
+        self.sample_f64(rng) as f32
+    }
+}
+
+/// Normal distribution restricted to the range [low, high]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TruncatedNormal {
+    normal: Normal,
+    low: f64,
+    high: f64,
+}
+
+impl TruncatedNormal {
+    /// Create a normal distribution N(mean, std^2) truncated to [low, high]
+    /// NOTE: `low` MUST be less than `high`, else returns an error.
+    /// Either bound may be infinite, to only truncate one side.
+    ///
+    /// NOTE: A `std` of 0 always samples the mean, so the mean MUST be
+    /// within [low, high] then, else returns an error.
+    pub fn new(mean: f64, std: f64, low: f64, high: f64) -> Result<Self, MatrixError> {
+        let normal = Normal::new(mean, std)?;
+
+        // Validity check for a non empty range (also rejects NaN's), that is
+        // still non empty once it's measured in std's from the mean
+        let valid = match std == 0.0 {
+            true => low < high && (low..=high).contains(&mean),
+            false => low < high && (low - mean) / std < (high - mean) / std,
+        };
+        match valid {
+            true => Ok(TruncatedNormal { normal, low, high }),
+            false => Err(MatrixError::InvalidParameter(
+                "truncated normal range must have low < high (and hold the mean when std is 0)"
+                    .to_string(),
+            )),
+        }
+    }
+
+    /// Create a normal distribution N(mean, std^2) truncated to 2 std's
+    /// either side of the mean, the usual choice for weight initialization
+    pub fn two_sigma(mean: f64, std: f64) -> Result<Self, MatrixError> {
+        TruncatedNormal::new(mean, std, mean - 2.0 * std, mean + 2.0 * std)
+    }
+
+    fn sample_f64(&self, rng: &mut Rng) -> f64 {
+        let Normal { mean, std } = self.normal;
+        if std == 0.0 {
+            return mean;
+        }
+
+        // Sample the standard normal truncated to the standardized range
+        let (low, high) = ((self.low - mean) / std, (self.high - mean) / std);
+        let z = match high <= 0.0 {
+            // Mirror a range left of the mean, so the tail samplers only see the right tail
+            true => -standard_truncated_normal(rng, -high, -low),
+            false => standard_truncated_normal(rng, low, high),
+        };
+
+        (mean + std * z).clamp(self.low, self.high)
+    }
+}
+impl Distribution<f64> for TruncatedNormal {
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        self.sample_f64(rng)
+    }
+}
+impl Distribution<f32> for TruncatedNormal {
+    fn sample(&self, rng: &mut Rng) -> f32 {
+        self.sample_f64(rng) as f32
+    }
+}
+
+/// Sample the standard normal truncated to [low, high] (with `high` > 0), by rejection
+/// from the proposal of C. P. Robert, "Simulation of truncated normal variables" (1995).
+///
+/// NOTE: Each proposal picks the cheapest of a normal, uniform or (shifted) exponential
+/// proposal for the range, so every draw is accepted with a probability bounded away
+/// from 0, however narrow the range is or however far out in the tail it sits.
+fn standard_truncated_normal(rng: &mut Rng, low: f64, high: f64) -> f64 {
+    let standard = Normal {
+        mean: 0.0,
+        std: 1.0,
+    };
+    if low <= 0.0 {
+        // A range holding the mean and at least sqrt(2π) wide has enough mass
+        // for plain rejection, a narrower one gets a uniform proposal
+        if high - low >= (2.0 * std::f64::consts::PI).sqrt() {
+            loop {
+                let z = standard.sample_f64(rng);
+                if (low..=high).contains(&z) {
+                    return z;
+                }
+            }
+        }
+        loop {
+            let z = low + (high - low) * rng.next_f64();
+            if rng.next_f64() < (-0.5 * z * z).exp() {
+                return z;
+            }
+        }
+    }
+
+    // Optimal rate of an exponential proposal starting at `low`,
+    // (low + sqrt(low^2 + 4)) / 2 without overflowing for a huge `low`
+    let root = low.hypot(2.0);
+    let rate = 0.5 * (low + root);
+    // Widest range a uniform proposal beats the exponential one for,
+    // 2 sqrt(e) / (low + root) * exp((low^2 - low * root) / 4)
+    let uniform_width = 2.0 * 0.5_f64.exp() / (low + root) * (-low / (low + root)).exp();
+
+    match high - low <= uniform_width {
+        true => loop {
+            let z = low + (high - low) * rng.next_f64();
+            // exp((low^2 - z^2) / 2), factored so it can't overflow
+            if rng.next_f64() < ((low - z) * (0.5 * low + 0.5 * z)).exp() {
+                return z;
+            }
+        },
+        false => loop {
+            // 1 - [0, 1) is (0, 1], so the log never sees a 0
+            let z = low - (1.0 - rng.next_f64()).ln() / rate;
+            if z <= high && rng.next_f64() < (-0.5 * (z - rate) * (z - rate)).exp() {
+                return z;
+            }
+        },
+    }
+}
+
+/// Bernoulli distribution, 1 with probability `p` and 0 otherwise
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bernoulli {
+    p: f64,
+}
+
+impl Bernoulli {
+    /// Create a Bernoulli distribution with a probability `p` of drawing a 1
+    /// NOTE: `p` MUST be within [0, 1], else returns an error
+    pub fn new(p: f64) -> Result<Self, MatrixError> {
+        // Validity check that `p` is a probability (also rejects NaN's)
+        match (0.0..=1.0).contains(&p) {
+            true => Ok(Bernoulli { p }),
+            false => Err(MatrixError::InvalidParameter(
+                "bernoulli probability must be within [0, 1]".to_string(),
+            )),
+        }
+    }
+}
+impl<T: From<u8>> Distribution<T> for Bernoulli {
+    fn sample(&self, rng: &mut Rng) -> T {
+        T::from((rng.next_f64() < self.p) as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// Verify the same seed always gives back the same sequence
+    fn test_seeded_rng_is_reproducible() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        assert_eq!(a.random_vec::<i64>(100), b.random_vec::<i64>(100));
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    /// Verify the first outputs against the SplitMix64 reference implementation
+    fn test_splitmix64_reference() {
+        let mut seed = 1234567;
+        let expected = [
+            6457827717110365317,
+            3203168211198807973,
+            9817491932198370423,
+        ];
+
+        expected
+            .iter()
+            .for_each(|&value| assert_eq!(splitmix64(&mut seed), value));
+    }
+
+    #[test]
+    /// Verify the first outputs against the xoshiro256** reference implementation
+    fn test_xoshiro256_reference() {
+        let mut rng = Rng {
+            state: [1, 2, 3, 4],
+        };
+        let expected = [
+            11520,
+            0,
+            1509978240,
+            1215971899390074240,
+            1216172134540287360,
+            607988272756665600,
+            16172922978634559625,
+            8476171486693032832,
+            10595114339597558777,
+            2904607092377533576,
+        ];
+
+        expected
+            .iter()
+            .for_each(|&value| assert_eq!(rng.next_u64(), value));
+    }
+
+    #[test]
+    /// Verify floats are in [0, 1) with the mean (1/2) and variance (1/12) of a uniform
+    fn test_f64_mean_and_variance() {
+        let n = 100_000;
+        let samples: Vec<f64> = Rng::new(42).random_vec(n);
+
+        assert!(samples.iter().all(|x| (0.0..1.0).contains(x)));
+
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        // ~5 standard errors either way
+        assert!((mean - 0.5).abs() < 5e-3, "mean {mean}");
+        assert!((variance - 1.0 / 12.0).abs() < 2e-3, "variance {variance}");
+    }
+
+    #[test]
+    /// Verify floats spread evenly over 20 buckets (chi-square goodness of fit)
+    fn test_f64_chi_square_buckets() {
+        const BUCKETS: usize = 20;
+        let n = 100_000;
+        let mut rng = Rng::new(7);
+
+        let mut counts = [0usize; BUCKETS];
+        (0..n).for_each(|_| counts[(rng.next_f64() * BUCKETS as f64) as usize] += 1);
+
+        let expected = n as f64 / BUCKETS as f64;
+        let chi_square: f64 = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+
+        // Critical value for 19 degrees of freedom at p = 0.001
+        assert!(chi_square < 43.82, "chi-square {chi_square}");
+    }
+
+    #[test]
+    /// Verify every bit of the raw output is set about half the time
+    fn test_u64_bit_balance() {
+        let n = 10_000;
+        let mut rng = Rng::new(3);
+
+        let mut ones = [0usize; 64];
+        (0..n).for_each(|_| {
+            let value = rng.next_u64();
+            (0..64).for_each(|bit| ones[bit] += ((value >> bit) & 1) as usize);
+        });
+
+        assert!(ones.iter().all(|&count| count.abs_diff(n / 2) < 250));
+    }
+
+    #[test]
+    /// Verify integers stay in their small non negative default range
+    fn test_random_int_default_range() {
+        let mut rng = Rng::new(23);
+
+        let u8s: Vec<u8> = rng.random_vec(10_000);
+        assert!(u8s.iter().all(|&x| x < 10) && u8s.contains(&0) && u8s.contains(&9));
+
+        let i32s: Vec<i32> = rng.random_vec(10_000);
+        assert!(i32s.iter().all(|x| (0..5_000).contains(x)));
+
+        let i64s: Vec<i64> = rng.random_vec(10_000);
+        assert!(i64s.iter().all(|x| (0..50_000).contains(x)));
+        assert!(i64s.iter().any(|&x| x > 45_000));
+
+        let i128s: Vec<i128> = rng.random_vec(1_000);
+        assert!(i128s.iter().all(|x| (0..50_000).contains(x)));
+    }
+
+    #[test]
+    fn test_random_f32_unit_interval() {
+        let n = 100_000;
+        let samples: Vec<f32> = Rng::new(29).random_vec(n);
+
+        assert!(samples.iter().all(|x| (0.0..1.0).contains(x)));
+        let mean = samples.iter().map(|&x| x as f64).sum::<f64>() / n as f64;
+        assert!((mean - 0.5).abs() < 5e-3, "mean {mean}");
+    }
+
+    #[test]
+    /// Verify back to back unseeded generators don't repeat each other
+    fn test_from_entropy_differs() {
+        let a: Vec<i64> = gen_rand_vec(16);
+        let b: Vec<i64> = gen_rand_vec(16);
+
+        assert_ne!(a, b);
+    }
+
+    /// Mean and (population) variance of some samples
+    fn moments(samples: &[f64]) -> (f64, f64) {
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let variance =
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+        (mean, variance)
+    }
+
+    #[test]
+    fn test_uniform_float_range() {
+        let uniform = Uniform::new(-3.0, 5.0).unwrap();
+        let mut rng = Rng::new(11);
+        let samples: Vec<f64> = (0..100_000).map(|_| rng.sample(&uniform)).collect();
+
+        let (mean, variance) = moments(&samples);
+
+        assert!(samples.iter().all(|x| (-3.0..5.0).contains(x)));
+        assert!((mean - 1.0).abs() < 0.05, "mean {mean}");
+        assert!((variance - 64.0 / 12.0).abs() < 0.1, "variance {variance}");
+    }
+
+    #[test]
+    /// Verify integers cover the whole range evenly (chi-square goodness of fit)
+    fn test_uniform_int_chi_square() {
+        let uniform = Uniform::new(-3_i32, 4).unwrap();
+        let mut rng = Rng::new(5);
+        let n = 70_000;
+
+        let mut counts = [0usize; 7];
+        (0..n).for_each(|_| counts[(rng.sample(&uniform) + 3) as usize] += 1);
+
+        let expected = n as f64 / 7.0;
+        let chi_square: f64 = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+
+        // Critical value for 6 degrees of freedom at p = 0.001
+        assert!(chi_square < 22.46, "chi-square {chi_square}");
+    }
+
+    #[test]
+    /// Verify ranges as wide as the type itself don't overflow
+    fn test_uniform_int_full_width() {
+        let mut rng = Rng::new(9);
+
+        let i8s = Uniform::new(i8::MIN, i8::MAX).unwrap();
+        let u128s = Uniform::new(0, u128::MAX).unwrap();
+        let i128s = Uniform::new(i128::MIN, i128::MAX).unwrap();
+
+        let samples: Vec<i8> = (0..10_000).map(|_| rng.sample(&i8s)).collect();
+        assert!(samples.contains(&i8::MIN) && samples.contains(&(i8::MAX - 1)));
+        assert!(!samples.contains(&i8::MAX));
+
+        assert!((0..100).any(|_| rng.sample(&u128s) > u64::MAX as u128));
+        assert!((0..100).any(|_| rng.sample(&i128s) < i64::MIN as i128));
+    }
+
+    #[test]
+    /// Verify inclusive ranges can give back `high`, even when it's the largest value of the type
+    fn test_uniform_inclusive() {
+        let mut rng = Rng::new(31);
+
+        let i8s = Uniform::new_inclusive(i8::MIN, i8::MAX).unwrap();
+        let samples: Vec<i8> = (0..10_000).map(|_| rng.sample(&i8s)).collect();
+        assert!(samples.contains(&i8::MIN) && samples.contains(&i8::MAX));
+
+        let u128s = Uniform::new_inclusive(0, u128::MAX).unwrap();
+        assert!((0..100).any(|_| rng.sample(&u128s) > u64::MAX as u128));
+        let i128s = Uniform::new_inclusive(i128::MIN, i128::MAX).unwrap();
+        assert!((0..100).any(|_| rng.sample(&i128s) < i64::MIN as i128));
+
+        let single = Uniform::new_inclusive(7_u32, 7).unwrap();
+        assert!((0..100).all(|_| rng.sample(&single) == 7));
+        let single = Uniform::new_inclusive(0.5, 0.5).unwrap();
+        assert!((0..100).all(|_| rng.sample(&single) == 0.5));
+
+        let floats = Uniform::new_inclusive(-1.0, 1.0).unwrap();
+        assert!((0..1_000).all(|_| (-1.0..=1.0).contains(&rng.sample(&floats))));
+
+        assert!(Uniform::new_inclusive(3, 2).is_err());
+        assert!(Uniform::new_inclusive(0.0, f64::INFINITY).is_err());
+        assert!(Uniform::new_inclusive(f64::INFINITY, f64::INFINITY).is_err());
+        assert!(Uniform::new_inclusive(f64::NAN, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_normal_moments() {
+        let normal = Normal::new(2.0, 3.0).unwrap();
+        let mut rng = Rng::new(13);
+        let samples: Vec<f64> = (0..100_000).map(|_| rng.sample(&normal)).collect();
+
+        let (mean, variance) = moments(&samples);
+
+        assert!((mean - 2.0).abs() < 0.05, "mean {mean}");
+        assert!(
+            (variance.sqrt() - 3.0).abs() < 0.05,
+            "std {}",
+            variance.sqrt()
+        );
+        // ~68% of samples are within 1 std of the mean
+        let within = samples.iter().filter(|x| (*x - 2.0).abs() < 3.0).count();
+        assert!((within as f64 / 100_000.0 - 0.6827).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_truncated_normal_bounds() {
+        let truncated = TruncatedNormal::two_sigma(0.0, 1.0).unwrap();
+        let mut rng = Rng::new(17);
+        let samples: Vec<f64> = (0..50_000).map(|_| rng.sample(&truncated)).collect();
+
+        let (mean, variance) = moments(&samples);
+
+        assert!(samples.iter().all(|x| (-2.0..=2.0).contains(x)));
+        assert!(mean.abs() < 0.02, "mean {mean}");
+        // Truncating at 2 std's leaves a variance of ~0.774
+        assert!((variance - 0.774).abs() < 0.02, "variance {variance}");
+    }
+
+    #[test]
+    /// Verify ranges far out in a tail, or too narrow for plain rejection, still sample
+    fn test_truncated_normal_tails() {
+        let mut rng = Rng::new(23);
+        let mut samples = |truncated: TruncatedNormal| -> Vec<f64> {
+            (0..10_000).map(|_| rng.sample(&truncated)).collect()
+        };
+
+        let right_tail = samples(TruncatedNormal::new(0.0, 1.0, 40.0, 41.0).unwrap());
+        let left_tail = samples(TruncatedNormal::new(0.0, 1.0, -41.0, -40.0).unwrap());
+        let narrow = samples(TruncatedNormal::new(0.0, 1.0, 5.0, 5.001).unwrap());
+        let open_ended = samples(TruncatedNormal::new(2.0, 0.5, 10.0, f64::INFINITY).unwrap());
+
+        assert!(right_tail.iter().all(|x| (40.0..=41.0).contains(x)));
+        // Almost all the mass of a tail sits right by its start, the mean is ~40.025
+        let (mean, _) = moments(&right_tail);
+        assert!((mean - 40.025).abs() < 0.005, "mean {mean}");
+        assert!(left_tail.iter().all(|x| (-41.0..=-40.0).contains(x)));
+        assert!(narrow.iter().all(|x| (5.0..=5.001).contains(x)));
+        assert!(open_ended.iter().all(|x| *x >= 10.0 && x.is_finite()));
+    }
+
+    #[test]
+    /// Verify a std of 0 samples the mean, when the range holds it
+    fn test_truncated_normal_zero_std() {
+        let mut rng = Rng::new(29);
+        let truncated = TruncatedNormal::new(1.5, 0.0, 1.0, 2.0).unwrap();
+
+        assert_eq!(rng.sample::<f64, _>(&truncated), 1.5);
+        assert!(TruncatedNormal::new(3.0, 0.0, 1.0, 2.0).is_err());
+    }
+
+    #[test]
+    /// Verify a range wider than the largest float samples without overflowing
+    fn test_uniform_float_full_range() {
+        let mut rng = Rng::new(31);
+        let uniform = Uniform::new(-f64::MAX, f64::MAX).unwrap();
+        let uniform_f32 = Uniform::new(f32::MIN, f32::MAX).unwrap();
+
+        let samples: Vec<f64> = (0..10_000).map(|_| rng.sample(&uniform)).collect();
+        let samples_f32: Vec<f32> = (0..10_000).map(|_| rng.sample(&uniform_f32)).collect();
+
+        assert!(samples.iter().all(|x| x.is_finite()));
+        assert!(samples.iter().any(|&x| x < -1e307) && samples.iter().any(|&x| x > 1e307));
+        assert!(samples_f32.iter().all(|x| x.is_finite()));
+        // Half the range is above 0
+        let positive = samples.iter().filter(|&&x| x > 0.0).count();
+        assert!((positive as f64 / 10_000.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_bernoulli_rate() {
+        let bernoulli = Bernoulli::new(0.3).unwrap();
+        let mut rng = Rng::new(19);
+
+        let ones: u32 = (0..100_000).map(|_| rng.sample::<u32, _>(&bernoulli)).sum();
+
+        assert!((ones as f64 / 100_000.0 - 0.3).abs() < 0.01);
+        assert_eq!(rng.sample::<f64, _>(&Bernoulli::new(1.0).unwrap()), 1.0);
+        assert_eq!(rng.sample::<f64, _>(&Bernoulli::new(0.0).unwrap()), 0.0);
+    }
+
+    #[test]
+    /// Verify invalid parameters are reported instead of panicking (or looping) later
+    fn test_invalid_distribution_parameters() {
+        assert!(Uniform::new(1.0, 1.0).is_err());
+        assert!(Uniform::new(f64::NAN, 1.0).is_err());
+        assert!(Uniform::new(0.0, f64::INFINITY).is_err());
+        assert!(Uniform::new(f32::NEG_INFINITY, 0.0).is_err());
+        assert!(Uniform::new(5, 2).is_err());
+        assert!(Normal::new(0.0, -1.0).is_err());
+        assert!(Normal::new(f64::INFINITY, 1.0).is_err());
+        assert!(TruncatedNormal::new(0.0, 1.0, 2.0, -2.0).is_err());
+        assert!(TruncatedNormal::new(0.0, 1.0, f64::NAN, 1.0).is_err());
+        assert!(Bernoulli::new(1.5).is_err());
+        assert!(Bernoulli::new(f64::NAN).is_err());
     }
 }
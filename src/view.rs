@@ -0,0 +1,657 @@
+use crate::gemm::{gemm, Operand};
+use crate::{Layout, Matrix, MatrixError};
+use std::ops::{Add, Mul, Range};
+
+/// Shape and strides of a view into the data of a `Matrix`,
+/// element (row, col) lives at `row * row_stride + col * col_stride`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Strides {
+    row_size: usize,
+    col_size: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl Strides {
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        match row < self.row_size && col < self.col_size {
+            true => Some(row * self.row_stride + col * self.col_stride),
+            false => None,
+        }
+    }
+
+    /// Indexes of every element in row major order
+    fn indexes(self) -> impl Iterator<Item = usize> {
+        (0..self.row_size).flat_map(move |row| {
+            (0..self.col_size).map(move |col| row * self.row_stride + col * self.col_stride)
+        })
+    }
+
+    fn transpose(self) -> Strides {
+        Strides {
+            row_size: self.col_size,
+            col_size: self.row_size,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+}
+
+/// Work out which part of a `Matrix`'s data a strided view covers, starting at
+/// (row, col) and taking `shape` elements, `step` rows/columns apart.
+/// NOTE: Returns None if the view doesn't fit inside the `Matrix`.
+fn strided_range(
+    matrix_shape: (usize, usize),
+    matrix_strides: (usize, usize),
+    start: (usize, usize),
+    shape: (usize, usize),
+    step: (usize, usize),
+) -> Option<(Range<usize>, Strides)> {
+    let (rows, cols) = matrix_shape;
+    let (row_stride, col_stride) = matrix_strides;
+
+    // Validity check that the view starts inside the `Matrix` and moves forward
+    if start.0 > rows || start.1 > cols || step.0 == 0 || step.1 == 0 {
+        return None;
+    }
+
+    let strides = Strides {
+        row_size: shape.0,
+        col_size: shape.1,
+        row_stride: step.0 * row_stride,
+        col_stride: step.1 * col_stride,
+    };
+    let offset = start.0 * row_stride + start.1 * col_stride;
+
+    // An empty view doesn't cover any data
+    if shape.0 == 0 || shape.1 == 0 {
+        return Some((offset.min(rows * cols)..offset.min(rows * cols), strides));
+    }
+
+    // Validity check that the last row and column are inside the `Matrix`
+    let last_row = start.0 + (shape.0 - 1) * step.0;
+    let last_col = start.1 + (shape.1 - 1) * step.1;
+    if last_row >= rows || last_col >= cols {
+        return None;
+    }
+
+    Some((
+        offset..last_row * row_stride + last_col * col_stride + 1,
+        strides,
+    ))
+}
+
+/// A borrowed, read only view into (part of) a `Matrix` without copying its data.
+///
+/// Create one with `Matrix::view`, `Matrix::row_view`, `Matrix::column_view`,
+/// `Matrix::block_view` or `Matrix::strided_view`.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    strides: Strides,
+}
+
+/// A borrowed, mutable view into (part of) a `Matrix`, writes go straight
+/// through to the underlying `Matrix`.
+///
+/// Create one with `Matrix::view_mut`, `Matrix::row_view_mut`, `Matrix::column_view_mut`,
+/// `Matrix::block_view_mut` or `Matrix::strided_view_mut`.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    data: &'a mut [T],
+    strides: Strides,
+}
+
+impl<T> Matrix<T> {
+    /// View the whole `Matrix`
+    pub fn view(&self) -> MatrixView<'_, T> {
+        self.strided_view((0, 0), self.shape(), (1, 1))
+            .expect("a full view always fits")
+    }
+
+    /// View a single row of the `Matrix` as a 1xN view
+    pub fn row_view(&self, row: usize) -> Option<MatrixView<'_, T>> {
+        match row < self.row_size {
+            true => self.block_view(row, 0, 1, self.col_size),
+            false => None,
+        }
+    }
+
+    /// View a single column of the `Matrix` as a Mx1 view
+    pub fn column_view(&self, col: usize) -> Option<MatrixView<'_, T>> {
+        match col < self.col_size {
+            true => self.block_view(0, col, self.row_size, 1),
+            false => None,
+        }
+    }
+
+    /// View a `rows` x `cols` block of the `Matrix` starting at (row, col)
+    /// NOTE: Returns None if the block doesn't fit inside the `Matrix`.
+    pub fn block_view(
+        &self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Option<MatrixView<'_, T>> {
+        self.strided_view((row, col), (rows, cols), (1, 1))
+    }
+
+    /// View `shape` (rows, columns) elements of the `Matrix` starting at `start`,
+    /// skipping ahead `step` (rows, columns) between them (e.g. every other row).
+    /// NOTE: Returns None if the view doesn't fit inside the `Matrix` or a step is 0.
+    pub fn strided_view(
+        &self,
+        start: (usize, usize),
+        shape: (usize, usize),
+        step: (usize, usize),
+    ) -> Option<MatrixView<'_, T>> {
+        let (range, strides) = strided_range(self.shape(), self.strides(), start, shape, step)?;
+        Some(MatrixView {
+            data: &self.data[range],
+            strides,
+        })
+    }
+
+    /// Split the `Matrix` into views of (at most) `batch_size` rows each,
+    /// the last one holding whatever rows are left over.
+    /// NOTE: Panics if `batch_size` is 0.
+    pub fn row_batches(&self, batch_size: usize) -> impl Iterator<Item = MatrixView<'_, T>> {
+        assert!(batch_size > 0, "batch_size must be greater than 0");
+
+        let col_size = self.col_size;
+        (0..self.row_size).step_by(batch_size).map(move |row| {
+            let rows = batch_size.min(self.row_size - row);
+            self.block_view(row, 0, rows, col_size)
+                .expect("batch is inside the matrix")
+        })
+    }
+
+    /// Mutably view the whole `Matrix`
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let shape = self.shape();
+        self.strided_view_mut((0, 0), shape, (1, 1))
+            .expect("a full view always fits")
+    }
+
+    /// Mutably view a single row of the `Matrix` as a 1xN view
+    pub fn row_view_mut(&mut self, row: usize) -> Option<MatrixViewMut<'_, T>> {
+        match row < self.row_size {
+            true => self.block_view_mut(row, 0, 1, self.col_size),
+            false => None,
+        }
+    }
+
+    /// Mutably view a single column of the `Matrix` as a Mx1 view
+    pub fn column_view_mut(&mut self, col: usize) -> Option<MatrixViewMut<'_, T>> {
+        match col < self.col_size {
+            true => self.block_view_mut(0, col, self.row_size, 1),
+            false => None,
+        }
+    }
+
+    /// Mutably view a `rows` x `cols` block of the `Matrix` starting at (row, col)
+    /// NOTE: Returns None if the block doesn't fit inside the `Matrix`.
+    pub fn block_view_mut(
+        &mut self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Option<MatrixViewMut<'_, T>> {
+        self.strided_view_mut((row, col), (rows, cols), (1, 1))
+    }
+
+    /// Mutably view `shape` (rows, columns) elements of the `Matrix` starting at
+    /// `start`, skipping ahead `step` (rows, columns) between them.
+    /// NOTE: Returns None if the view doesn't fit inside the `Matrix` or a step is 0.
+    pub fn strided_view_mut(
+        &mut self,
+        start: (usize, usize),
+        shape: (usize, usize),
+        step: (usize, usize),
+    ) -> Option<MatrixViewMut<'_, T>> {
+        let (range, strides) = strided_range(self.shape(), self.strides(), start, shape, step)?;
+        Some(MatrixViewMut {
+            data: &mut self.data[range],
+            strides,
+        })
+    }
+
+    /// Split the `Matrix` into mutable views of (at most) `batch_size` rows each,
+    /// the last one holding whatever rows are left over.
+    /// NOTE: Panics if `batch_size` is 0.
+    /// NOTE: Only a row major `Matrix` splits into disjoint batches, so this returns an
+    /// error for a column major one (use `Matrix::into_layout(Layout::RowMajor)` first).
+    pub fn row_batches_mut(
+        &mut self,
+        batch_size: usize,
+    ) -> Result<impl Iterator<Item = MatrixViewMut<'_, T>>, MatrixError> {
+        assert!(batch_size > 0, "batch_size must be greater than 0");
+
+        // Validity check that every batch owns its own stretch of the data
+        if self.layout == Layout::ColumnMajor {
+            return Err(MatrixError::InvalidParameter(
+                "row batches of a column major matrix overlap, convert it to row major first"
+                    .to_string(),
+            ));
+        }
+
+        let (row_size, col_size) = self.shape();
+        // Rows are contiguous, so every batch is its own chunk of the data
+        let mut rest = &mut self.data[..];
+        Ok((0..row_size).step_by(batch_size).map(move |row| {
+            let rows = batch_size.min(row_size - row);
+            let (chunk, tail) = std::mem::take(&mut rest).split_at_mut(rows * col_size);
+            rest = tail;
+            MatrixViewMut {
+                strides: Strides {
+                    row_size: rows,
+                    col_size,
+                    row_stride: col_size,
+                    col_stride: 1,
+                },
+                data: chunk,
+            }
+        }))
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// Borrow the view as an operand for the matrix multiplication kernel
+    fn operand(&self) -> Operand<'a, T> {
+        Operand {
+            data: self.data,
+            row_stride: self.strides.row_stride,
+            col_stride: self.strides.col_stride,
+        }
+    }
+
+    /// Get the (rows, columns) shape of the view
+    pub fn shape(&self) -> (usize, usize) {
+        (self.strides.row_size, self.strides.col_size)
+    }
+
+    /// Get a reference to the element at (row, col) of the view
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        self.strides.index(row, col).map(|idx| &self.data[idx])
+    }
+
+    /// Iterate over the elements of the view in row major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        let data = self.data;
+        self.strides.indexes().map(move |idx| &data[idx])
+    }
+
+    /// Swap the rows and columns of the view, without moving any data
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            strides: self.strides.transpose(),
+        }
+    }
+
+    /// Copy the viewed elements into a new `Matrix`
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        Matrix {
+            data: self.iter().cloned().collect(),
+            row_size: self.strides.row_size,
+            col_size: self.strides.col_size,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Perform a summation over the view
+    pub fn sum(&self) -> T
+    where
+        T: Default + Clone + Add<Output = T>,
+    {
+        self.iter().cloned().fold(T::default(), |acc, x| acc + x)
+    }
+
+    /// Compute the sum of all diagonal elements in the view
+    pub fn trace(&self) -> T
+    where
+        T: Default + Clone + Add<Output = T>,
+    {
+        (0..self.strides.row_size.min(self.strides.col_size))
+            .filter_map(|idx| self.get(idx, idx))
+            .cloned()
+            .fold(T::default(), |acc, x| acc + x)
+    }
+
+    /// Compute the frobenius norm of the view
+    pub fn frobenius_norm(&self) -> f64
+    where
+        T: Clone + Into<f64>,
+    {
+        self.iter()
+            .map(|val| {
+                let val_f64: f64 = val.clone().into();
+                val_f64 * val_f64
+            })
+            .fold(f64::default(), |acc, x| acc + x)
+            .sqrt()
+    }
+
+    /// Multiply the view with another view using standard matrix multiplication
+    /// NOTE: The views inner dimensions MUST match else returns None
+    pub fn multiply(&self, multiplier: &MatrixView<'_, T>) -> Option<Matrix<T>>
+    where
+        T: Default + Clone + Add<Output = T> + Mul<Output = T>,
+    {
+        let (rows, inner) = self.shape();
+        let (multiplier_rows, cols) = multiplier.shape();
+
+        // Validity check for the views inner dimensions
+        if inner != multiplier_rows {
+            return None;
+        }
+
+        let data = gemm(&self.operand(), &multiplier.operand(), rows, inner, cols);
+
+        Some(Matrix {
+            data,
+            row_size: rows,
+            col_size: cols,
+            layout: Layout::RowMajor,
+        })
+    }
+}
+
+impl<T> MatrixViewMut<'_, T> {
+    /// Get the (rows, columns) shape of the view
+    pub fn shape(&self) -> (usize, usize) {
+        (self.strides.row_size, self.strides.col_size)
+    }
+
+    /// Reborrow as a read only view
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data,
+            strides: self.strides,
+        }
+    }
+
+    /// Get a reference to the element at (row, col) of the view
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.strides.index(row, col).map(|idx| &self.data[idx])
+    }
+
+    /// Get a mutable reference to the element at (row, col) of the view
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.strides.index(row, col).map(|idx| &mut self.data[idx])
+    }
+
+    /// Apply `f` to every viewed element in place
+    pub fn map_inplace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        self.strides
+            .indexes()
+            .for_each(|idx| f(&mut self.data[idx]));
+    }
+
+    /// Set every viewed element to `value`
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.map_inplace(|x| *x = value.clone());
+    }
+
+    /// Overwrite the viewed elements with the elements of `source`
+    /// NOTE: Both MUST have the same dimensionality, else returns an error
+    pub fn copy_from(&mut self, source: &MatrixView<'_, T>) -> Result<(), MatrixError>
+    where
+        T: Clone,
+    {
+        // Validity check that both dimensions match
+        if self.shape() != source.shape() {
+            return Err(MatrixError::ShapeMismatch {
+                left: self.shape(),
+                right: source.shape(),
+            });
+        }
+
+        self.strides
+            .indexes()
+            .zip(source.iter())
+            .for_each(|(idx, value)| self.data[idx] = value.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix_3x4() -> Matrix<i32> {
+        Matrix {
+            data: (1..=12).collect(),
+            row_size: 3,
+            col_size: 4,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    #[test]
+    fn test_row_and_column_views() {
+        let matrix = matrix_3x4();
+
+        let row = matrix.row_view(1).unwrap();
+        let column = matrix.column_view(2).unwrap();
+
+        assert_eq!(row.shape(), (1, 4));
+        assert_eq!(row.iter().copied().collect::<Vec<_>>(), vec![5, 6, 7, 8]);
+        assert_eq!(column.shape(), (3, 1));
+        assert_eq!(column.iter().copied().collect::<Vec<_>>(), vec![3, 7, 11]);
+        assert!(matrix.row_view(3).is_none());
+        assert!(matrix.column_view(4).is_none());
+    }
+
+    #[test]
+    fn test_block_view() {
+        let matrix = matrix_3x4();
+        let block = matrix.block_view(1, 1, 2, 2).unwrap();
+
+        assert_eq!(block.to_matrix().data, vec![6, 7, 10, 11]);
+        assert_eq!(block.get(1, 0), Some(&10));
+        assert_eq!(block.get(2, 0), None);
+        assert_eq!(block.sum(), 34);
+        assert_eq!(block.trace(), 17);
+        assert!(matrix.block_view(2, 2, 2, 2).is_none());
+    }
+
+    #[test]
+    fn test_strided_view() {
+        let matrix = matrix_3x4();
+        let every_other = matrix.strided_view((0, 1), (2, 2), (2, 2)).unwrap();
+
+        assert_eq!(every_other.to_matrix().data, vec![2, 4, 10, 12]);
+        assert!(matrix.strided_view((0, 0), (2, 2), (3, 1)).is_none());
+        assert!(matrix.strided_view((0, 0), (1, 1), (0, 1)).is_none());
+    }
+
+    #[test]
+    fn test_empty_view() {
+        let matrix = matrix_3x4();
+        let empty = matrix.block_view(3, 0, 0, 4).unwrap();
+
+        assert_eq!(empty.shape(), (0, 4));
+        assert_eq!(empty.sum(), 0);
+    }
+
+    #[test]
+    fn test_view_transpose_and_multiply() {
+        let matrix = matrix_3x4();
+        let view = matrix.block_view(0, 0, 2, 3).unwrap();
+
+        // (2x3) * (3x2)
+        let product = view.multiply(&view.transpose()).unwrap();
+        let expected = view
+            .to_matrix()
+            .multiply(&view.to_matrix().transpose())
+            .unwrap();
+
+        assert_eq!(product.shape(), (2, 2));
+        assert_eq!(product.data, expected.data);
+        assert!(view.multiply(&view).is_none());
+    }
+
+    #[test]
+    fn test_view_frobenius_norm() {
+        let matrix = Matrix {
+            data: vec![3.0, 9.0, 4.0, 9.0],
+            row_size: 2,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let column = matrix.column_view(0).unwrap();
+
+        assert!((column.frobenius_norm() - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_view_mut_writes_through() {
+        let mut matrix = matrix_3x4();
+
+        matrix.column_view_mut(0).unwrap().fill(0);
+        matrix
+            .block_view_mut(1, 2, 2, 2)
+            .unwrap()
+            .map_inplace(|x| *x *= 10);
+        *matrix.row_view_mut(0).unwrap().get_mut(0, 3).unwrap() = -1;
+
+        assert_eq!(
+            matrix.data,
+            vec![0, 2, 3, -1, 0, 6, 70, 80, 0, 10, 110, 120]
+        );
+    }
+
+    #[test]
+    fn test_view_mut_copy_from() {
+        let source = Matrix {
+            data: vec![1, 2],
+            row_size: 1,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+        let mut matrix = Matrix::<i32>::new(2, 2);
+
+        matrix
+            .column_view_mut(1)
+            .unwrap()
+            .copy_from(&source.view().transpose())
+            .unwrap();
+
+        assert_eq!(matrix.data, vec![0, 1, 0, 2]);
+        assert_eq!(
+            matrix.view_mut().copy_from(&source.view()),
+            Err(MatrixError::ShapeMismatch {
+                left: (2, 2),
+                right: (1, 2)
+            })
+        );
+    }
+
+    #[test]
+    /// Verify mini-batches cover every row, with a smaller last batch
+    fn test_row_batches() {
+        let matrix = Matrix {
+            data: (0..10).collect(),
+            row_size: 5,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        let shapes: Vec<(usize, usize)> = matrix.row_batches(2).map(|b| b.shape()).collect();
+        let sums: Vec<i32> = matrix.row_batches(2).map(|b| b.sum()).collect();
+
+        assert_eq!(shapes, vec![(2, 2), (2, 2), (1, 2)]);
+        assert_eq!(sums, vec![6, 22, 17]);
+    }
+
+    #[test]
+    fn test_row_batches_mut() {
+        let mut matrix = Matrix {
+            data: (0..6).collect(),
+            row_size: 3,
+            col_size: 2,
+            layout: Layout::RowMajor,
+        };
+
+        matrix
+            .row_batches_mut(2)
+            .unwrap()
+            .enumerate()
+            .for_each(|(idx, mut batch)| batch.fill(idx as i32));
+
+        assert_eq!(matrix.data, vec![0, 0, 0, 0, 1, 1]);
+    }
+
+    #[test]
+    /// Verify both batch iterators agree on the shapes of a matrix without columns
+    fn test_row_batches_no_columns() {
+        let mut matrix: Matrix<i32> = Matrix {
+            data: vec![],
+            row_size: 3,
+            col_size: 0,
+            layout: Layout::RowMajor,
+        };
+
+        let shapes: Vec<(usize, usize)> = matrix.row_batches(2).map(|b| b.shape()).collect();
+        let shapes_mut: Vec<(usize, usize)> = matrix
+            .row_batches_mut(2)
+            .unwrap()
+            .map(|b| b.as_view().shape())
+            .collect();
+
+        assert_eq!(shapes, vec![(2, 0), (1, 0)]);
+        assert_eq!(shapes_mut, shapes);
+    }
+
+    #[test]
+    #[should_panic(expected = "batch_size must be greater than 0")]
+    fn test_row_batches_zero_batch_size() {
+        let _ = matrix_3x4().row_batches(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "batch_size must be greater than 0")]
+    fn test_row_batches_mut_zero_batch_size() {
+        let _ = matrix_3x4().row_batches_mut(0);
+    }
+
+    #[test]
+    fn test_column_major_views() {
+        let mut matrix = matrix_3x4().into_layout(Layout::ColumnMajor);
+
+        assert_eq!(matrix.row_view(1).unwrap().iter().sum::<i32>(), 26);
+        assert_eq!(
+            matrix.block_view(1, 1, 2, 2).unwrap().to_matrix().data,
+            vec![6, 7, 10, 11]
+        );
+
+        matrix.row_view_mut(0).unwrap().fill(0);
+        assert_eq!(matrix.try_get_column(3), Some(vec![0, 8, 12]));
+
+        assert!(matches!(
+            matrix.row_batches_mut(2),
+            Err(MatrixError::InvalidParameter(_))
+        ));
+        assert_eq!(matrix.layout, Layout::ColumnMajor);
+
+        let mut matrix = matrix.into_layout(Layout::RowMajor);
+        let batches: Vec<i32> = matrix
+            .row_batches_mut(2)
+            .unwrap()
+            .map(|b| b.as_view().sum())
+            .collect();
+        assert_eq!(batches, vec![26, 42]);
+    }
+}